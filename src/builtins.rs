@@ -0,0 +1,31 @@
+use crate::codegen::Target;
+
+/** Function every program can call without defining it */
+pub struct Builtin {
+    pub name: &'static str,
+    /** Number of parameters, `None` when it takes any number of arguments */
+    pub parameters: Option<usize>,
+    python: &'static str,
+    javascript: &'static str,
+}
+
+impl Builtin {
+    /** Name of the function implementing the builtin on the target */
+    pub fn target_name(&self, target: Target) -> &'static str {
+        match target {
+            Target::Python => self.python,
+            Target::Javascript => self.javascript,
+        }
+    }
+}
+
+pub const BUILTINS: &[Builtin] = &[Builtin {
+    name: "print",
+    parameters: None,
+    python: "print",
+    javascript: "console.log",
+}];
+
+pub fn get_builtin(name: &str) -> Option<&'static Builtin> {
+    BUILTINS.iter().find(|builtin| builtin.name == name)
+}
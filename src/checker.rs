@@ -0,0 +1,363 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+use serde_json::json;
+
+use crate::{
+    builtins::BUILTINS,
+    diagnostic::{Diagnostic, DiagnosticCode},
+    parser::{
+        BinaryOperator, Block, Declaration, Expression, FormatStringPart, FunctionDef, Literal,
+        Program, SimpleType, Statement, UnaryOperator,
+    },
+    span::Span,
+    token::Token,
+    translation::{Message, TranslationKey},
+};
+
+/** Parameters and return type of a function that can be called */
+pub struct Signature {
+    /** Number of parameters, `None` when it takes any number of arguments */
+    pub parameters: Option<usize>,
+    pub return_type: Option<SimpleType>,
+    /** Name of the definition, `None` for builtins */
+    pub span: Option<Span>,
+}
+
+/** Functions visible from a block, including those of the enclosing blocks */
+pub struct Scope<'a> {
+    parent: Option<&'a Scope<'a>>,
+    functions: HashMap<String, Signature>,
+    /** Functions of the block whose definition the check hasn't reached yet */
+    pending: RefCell<HashSet<String>>,
+}
+
+impl<'a> Scope<'a> {
+    /** Top-level scope, with the builtins */
+    pub fn new() -> Self {
+        let functions = BUILTINS
+            .iter()
+            .map(|builtin| {
+                let signature = Signature {
+                    parameters: builtin.parameters,
+                    return_type: None,
+                    span: None,
+                };
+                (builtin.name.to_string(), signature)
+            })
+            .collect();
+
+        Scope {
+            parent: None,
+            functions,
+            pending: RefCell::default(),
+        }
+    }
+
+    /** Scope of a block, where every function it defines can be called */
+    pub fn with_statements(parent: &'a Scope<'a>, statements: &[Statement]) -> Self {
+        let functions = statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDef(function) => Some((
+                    function.name.name.clone(),
+                    Signature {
+                        parameters: Some(function.parameters.len()),
+                        return_type: function.return_type.as_ref().and_then(|union_type| {
+                            match union_type.types[..] {
+                                [simple_type] => Some(simple_type),
+                                _ => None,
+                            }
+                        }),
+                        span: Some(function.name.span),
+                    },
+                )),
+                _ => None,
+            })
+            .collect::<HashMap<String, Signature>>();
+
+        Scope {
+            parent: Some(parent),
+            pending: RefCell::new(functions.keys().cloned().collect()),
+            functions,
+        }
+    }
+
+    /**
+     * Whether the function is defined later in this block. Python only defines
+     * it once the `def` runs, while JavaScript hoists it, so such calls are
+     * rejected. Functions of enclosing blocks can be called from a body, which
+     * only runs once it is called.
+     */
+    fn is_pending(&self, name: &str) -> bool {
+        self.pending.borrow().contains(name)
+    }
+
+    /** Marks the statement as reached, defining the function it may declare */
+    fn reach(&self, statement: &Statement) {
+        if let Statement::FunctionDef(function) = statement {
+            self.pending.borrow_mut().remove(&function.name.name);
+        }
+    }
+
+    pub fn get_function(&self, name: &str) -> Option<&Signature> {
+        match self.functions.get(name) {
+            Some(signature) => Some(signature),
+            None => self.parent.and_then(|parent| parent.get_function(name)),
+        }
+    }
+}
+
+impl Default for Scope<'_> {
+    fn default() -> Self {
+        Scope::new()
+    }
+}
+
+pub trait Checkable {
+    fn check(&self, scope: &Scope) -> Vec<Diagnostic>;
+}
+
+impl Checkable for Declaration {
+    fn check(&self, scope: &Scope) -> Vec<Diagnostic> {
+        let mut diagnostics = self.value.check(scope);
+
+        let union_type = match &self.union_type {
+            Some(union_type) => union_type,
+            None => return diagnostics,
+        };
+
+        let inferred = match infer(&self.value, scope) {
+            Some(inferred) => inferred,
+            None => return diagnostics,
+        };
+
+        let compatible = match inferred {
+            SimpleType::Str => {
+                union_type.has_type(SimpleType::Str) || union_type.has_type(SimpleType::String)
+            }
+            _ => union_type.has_type(inferred),
+        };
+
+        if compatible {
+            return diagnostics;
+        }
+
+        let found = match &self.value {
+            Expression::Literal(Literal::StringLiteral(value, _)) => Token::String(value.clone()),
+            Expression::Literal(Literal::NumberLiteral(value, _)) => Token::Number(*value),
+            Expression::Literal(Literal::FloatLiteral(value, _)) => Token::Float(*value),
+            Expression::Literal(Literal::BigNumberLiteral(value, _)) => {
+                Token::BigNumber(value.clone())
+            }
+            Expression::Literal(Literal::BoolLiteral(value, _)) => Token::Bool(*value),
+            _ => Token::SimpleType(<&'static str>::from(&inferred).to_string()),
+        };
+
+        let expected: Vec<&'static str> = union_type.types.iter().map(|t| t.into()).collect();
+
+        diagnostics.push(
+            Diagnostic::error(
+                DiagnosticCode::MismatchedType,
+                Message::new(
+                    TranslationKey::MismatchedType,
+                    json!({ "expected": expected.join(" | "), "found": found.describe() }),
+                ),
+                self.value.span(),
+            )
+            .with_label(
+                union_type.span,
+                Message::new(
+                    TranslationKey::TypeDeclaredHere,
+                    json!({ "name": self.variable.name }),
+                ),
+            ),
+        );
+
+        diagnostics
+    }
+}
+
+impl Checkable for Expression {
+    fn check(&self, scope: &Scope) -> Vec<Diagnostic> {
+        match self {
+            Expression::Literal(_) | Expression::Variable(_) => vec![],
+            Expression::Unary { operand, .. } => operand.check(scope),
+            Expression::Binary { left, right, .. } => {
+                let mut diagnostics = left.check(scope);
+                diagnostics.extend(right.check(scope));
+                diagnostics
+            }
+            Expression::Call {
+                callee,
+                arguments,
+                span,
+            } => {
+                let mut diagnostics: Vec<Diagnostic> =
+                    arguments.iter().flat_map(|a| a.check(scope)).collect();
+
+                if scope.is_pending(&callee.name) {
+                    let diagnostic = Diagnostic::error(
+                        DiagnosticCode::CalledBeforeDefinition,
+                        Message::new(
+                            TranslationKey::CalledBeforeDefinition,
+                            json!({ "name": callee.name }),
+                        ),
+                        *span,
+                    );
+
+                    diagnostics.push(match scope.get_function(&callee.name) {
+                        Some(Signature {
+                            span: Some(definition),
+                            ..
+                        }) => diagnostic.with_label(
+                            *definition,
+                            Message::new(
+                                TranslationKey::FunctionDeclaredHere,
+                                json!({ "name": callee.name }),
+                            ),
+                        ),
+                        _ => diagnostic,
+                    });
+                }
+
+                if let Some(signature) = scope.get_function(&callee.name) {
+                    match signature.parameters {
+                        Some(parameters) if parameters != arguments.len() => {
+                            let diagnostic = Diagnostic::error(
+                                DiagnosticCode::WrongArgumentCount,
+                                Message::new(
+                                    TranslationKey::WrongArgumentCount,
+                                    json!({
+                                        "name": callee.name,
+                                        "expected": parameters,
+                                        "found": arguments.len()
+                                    }),
+                                ),
+                                *span,
+                            );
+
+                            diagnostics.push(match signature.span {
+                                Some(definition) => diagnostic.with_label(
+                                    definition,
+                                    Message::new(
+                                        TranslationKey::FunctionDeclaredHere,
+                                        json!({ "name": callee.name }),
+                                    ),
+                                ),
+                                None => diagnostic,
+                            });
+                        }
+                        _ => (),
+                    }
+                }
+
+                diagnostics
+            }
+            Expression::FormatString { parts, .. } => parts
+                .iter()
+                .flat_map(|part| match part {
+                    FormatStringPart::Text(_) => vec![],
+                    FormatStringPart::Expression(expression) => expression.check(scope),
+                })
+                .collect(),
+        }
+    }
+}
+
+/** Type of the expression, when it is known without looking at variables */
+fn infer(expression: &Expression, scope: &Scope) -> Option<SimpleType> {
+    match expression {
+        Expression::Literal(Literal::StringLiteral(..)) | Expression::FormatString { .. } => {
+            Some(SimpleType::Str)
+        }
+        Expression::Literal(
+            Literal::NumberLiteral(..) | Literal::FloatLiteral(..) | Literal::BigNumberLiteral(..),
+        ) => Some(SimpleType::Number),
+        Expression::Literal(Literal::BoolLiteral(..)) => Some(SimpleType::Bool),
+        Expression::Variable(_) => None,
+        Expression::Unary {
+            operator: UnaryOperator::Not,
+            ..
+        } => Some(SimpleType::Bool),
+        Expression::Unary { operand, .. } => match infer(operand, scope) {
+            Some(SimpleType::Number) => Some(SimpleType::Number),
+            _ => None,
+        },
+        Expression::Binary { operator, .. } if operator.is_comparison() => Some(SimpleType::Bool),
+        // Either operand can be the result, so the type is only known when they agree
+        Expression::Binary {
+            operator: BinaryOperator::And | BinaryOperator::Or,
+            left,
+            right,
+            ..
+        } => match (infer(left, scope), infer(right, scope)) {
+            (Some(left), Some(right)) if left == right => Some(left),
+            _ => None,
+        },
+        Expression::Binary {
+            operator,
+            left,
+            right,
+            ..
+        } => match (infer(left, scope), infer(right, scope)) {
+            (Some(SimpleType::Number), Some(SimpleType::Number)) => Some(SimpleType::Number),
+            (Some(SimpleType::Str), Some(SimpleType::Str)) if *operator == BinaryOperator::Add => {
+                Some(SimpleType::Str)
+            }
+            _ => None,
+        },
+        Expression::Call { callee, .. } => scope
+            .get_function(&callee.name)
+            .and_then(|signature| signature.return_type),
+    }
+}
+
+impl Checkable for Statement {
+    fn check(&self, scope: &Scope) -> Vec<Diagnostic> {
+        match self {
+            Statement::Declaration(declaration) => declaration.check(scope),
+            Statement::FunctionDef(function) => function.check(scope),
+            Statement::Expression(expression) => expression.check(scope),
+        }
+    }
+}
+
+impl Checkable for Block {
+    fn check(&self, scope: &Scope) -> Vec<Diagnostic> {
+        let scope = Scope::with_statements(scope, &self.statements);
+        self.statements
+            .iter()
+            .flat_map(|s| {
+                scope.reach(s);
+                s.check(&scope)
+            })
+            .collect()
+    }
+}
+
+impl Checkable for FunctionDef {
+    fn check(&self, scope: &Scope) -> Vec<Diagnostic> {
+        self.body.check(scope)
+    }
+}
+
+impl Checkable for Program {
+    fn check(&self, scope: &Scope) -> Vec<Diagnostic> {
+        let scope = Scope::with_statements(scope, &self.statements);
+        self.statements
+            .iter()
+            .flat_map(|s| {
+                scope.reach(s);
+                s.check(&scope)
+            })
+            .collect()
+    }
+}
+
+/** Checks the node with only the builtins in scope */
+pub fn check(node: &dyn Checkable) -> Vec<Diagnostic> {
+    node.check(&Scope::new())
+}
@@ -1,49 +1,832 @@
-use crate::parser::{Declaration, Literal, Variable};
+use std::{
+    cell::RefCell,
+    collections::{BTreeSet, HashMap},
+};
 
+use crate::{
+    builtins::{get_builtin, Builtin},
+    checker::{self, Signature},
+    diagnostic::Diagnostic,
+    parser::{
+        BinaryOperator, Block, Declaration, Expression, FormatStringPart, FunctionDef, Literal,
+        Program, Statement, UnaryOperator, Variable,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Target {
     Python,
     Javascript,
 }
 
+impl Target {
+    /** File extension of the generated source */
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Target::Python => "py",
+            Target::Javascript => "js",
+        }
+    }
+
+    /** Program used by `glare run` to execute the generated source */
+    pub fn interpreter(&self) -> &'static str {
+        match self {
+            Target::Python => "python3",
+            Target::Javascript => "node",
+        }
+    }
+}
+
+impl TryFrom<&str> for Target {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "python" | "py" => Ok(Target::Python),
+            "javascript" | "js" => Ok(Target::Javascript),
+            _ => Err(()),
+        }
+    }
+}
+
 pub trait Compilable {
-    fn compile(&self, target: Target) -> Option<String>;
+    fn compile(&self, target: Target, scope: &Scope) -> Result<String, Diagnostic>;
+}
+
+/** Variables and functions of the block being compiled */
+#[derive(Default)]
+pub struct Scope<'a> {
+    parent: Option<&'a Scope<'a>>,
+    /**
+     * Variables declared so far, with the kind of number they hold last, since
+     * JavaScript can't declare a variable twice nor mix `BigInt`s with numbers
+     */
+    variables: RefCell<HashMap<String, Numeric>>,
+    functions: checker::Scope<'a>,
+    /** Helpers the JavaScript output calls, only kept by the top-level scope */
+    helpers: RefCell<BTreeSet<Helper>>,
+}
+
+impl<'a> Scope<'a> {
+    /** Top-level scope, with the builtins */
+    pub fn new() -> Self {
+        Scope::default()
+    }
+
+    /** Scope of a block, where every function it defines can be called */
+    pub fn with_statements(parent: &'a Scope<'a>, statements: &[Statement]) -> Self {
+        Scope {
+            parent: Some(parent),
+            variables: RefCell::default(),
+            functions: checker::Scope::with_statements(&parent.functions, statements),
+            helpers: RefCell::default(),
+        }
+    }
+
+    /** Builtin called by the name, unless a function or a variable of the program shadows it */
+    fn get_builtin(&self, name: &str) -> Option<&'static Builtin> {
+        match self.functions.get_function(name) {
+            Some(Signature { span: Some(_), .. }) => None,
+            _ if self.variable(name).is_some() => None,
+            _ => get_builtin(name),
+        }
+    }
+
+    /**
+     * Marks the variable as declared in this block, holding a number of the
+     * kind, returns whether it wasn't already
+     */
+    fn declare(&self, name: &str, numeric: Numeric) -> bool {
+        self.variables
+            .borrow_mut()
+            .insert(name.to_string(), numeric)
+            .is_none()
+    }
+
+    /**
+     * Kind of value the variable holds, `None` when it isn't declared. Variables
+     * of enclosing blocks may be assigned again before a function reads them.
+     */
+    fn variable(&self, name: &str) -> Option<Numeric> {
+        match (self.variables.borrow().get(name), self.parent) {
+            (Some(numeric), _) => Some(*numeric),
+            (None, Some(parent)) => parent.variable(name).map(|_| Numeric::Unknown),
+            (None, None) => None,
+        }
+    }
+
+    /** Marks the helper as called by the output */
+    fn require(&self, helper: Helper) {
+        match self.parent {
+            Some(parent) => parent.require(helper),
+            None => {
+                self.helpers.borrow_mut().insert(helper);
+            }
+        }
+    }
+
+    /** Definitions of the helpers called by the output, one per line */
+    fn helpers(&self) -> String {
+        match self.parent {
+            Some(parent) => parent.helpers(),
+            None => self
+                .helpers
+                .borrow()
+                .iter()
+                .map(|helper| format!("{}\n", helper.definition()))
+                .collect(),
+        }
+    }
 }
 
 impl Compilable for Literal {
-    fn compile(&self, _: Target) -> Option<String> {
+    fn compile(&self, target: Target, _: &Scope) -> Result<String, Diagnostic> {
         match &self {
-            Literal::StringLiteral(value) => Some(value.to_string()),
-            Literal::NumberLiteral(value) => Some(format!("{}", value)),
+            Literal::StringLiteral(value, _) => Ok(value.to_string()),
+            Literal::NumberLiteral(value, _) => Ok(format!("{}", value)),
+            // Shortest form that reads back as the same value, always with a `.` or an exponent
+            Literal::FloatLiteral(value, _) => Ok(format!("{:?}", value)),
+            Literal::BigNumberLiteral(value, _) => Ok(value.to_string()),
+            Literal::BoolLiteral(value, _) => match (target, value) {
+                (Target::Python, true) => Ok("True".to_string()),
+                (Target::Python, false) => Ok("False".to_string()),
+                (Target::Javascript, _) => Ok(value.to_string()),
+            },
         }
     }
 }
 
-impl Compilable for Declaration {
-    fn compile(&self, target: Target) -> Option<String> {
-        match (&self.variable, &self.union_type) {
-            (Variable { name }, ..) => match target {
-                Target::Python => Some(format!(
-                    "{} = {}",
-                    *name,
-                    match &self.literal {
-                        Literal::StringLiteral(literal) => format!("\"{}\"", literal),
-                        Literal::NumberLiteral(literal) => format!("{}", literal),
+impl Compilable for Expression {
+    fn compile(&self, target: Target, scope: &Scope) -> Result<String, Diagnostic> {
+        match self {
+            Expression::Literal(Literal::StringLiteral(value, _)) => {
+                let multiline = value.contains('\n');
+
+                match (target, multiline) {
+                    (Target::Python, false) => Ok(format!("\"{}\"", escape(value, '"', false))),
+                    (Target::Python, true) => {
+                        Ok(format!("\"\"\"{}\"\"\"", escape(value, '"', true)))
                     }
+                    (Target::Javascript, _) => Ok(format!("`{}`", escape(value, '`', multiline))),
+                }
+            }
+            Expression::Unary {
+                operator: UnaryOperator::Not,
+                operand,
+                ..
+            } => match target {
+                Target::Python => Ok(format!(
+                    "not {}",
+                    wrap(
+                        operand,
+                        UnaryOperator::Not.precedence() + 1,
+                        target,
+                        scope,
+                        numeric(operand, scope)
+                    )?
                 )),
-                Target::Javascript => Some(format!(
-                    "let {} = {};",
-                    *name,
-                    match &self.literal {
-                        Literal::StringLiteral(literal) => format!("`{}`", literal),
-                        Literal::NumberLiteral(literal) => format!("{}", literal),
+                // `!` binds tighter than any binary operator in JavaScript
+                Target::Javascript => Ok(format!(
+                    "!{}",
+                    wrap(operand, u8::MAX, target, scope, numeric(operand, scope))?
+                )),
+            },
+            Expression::Binary {
+                operator,
+                left,
+                right,
+                ..
+            } if operator.is_comparison() => {
+                // Both sides have to be `BigInt`s or numbers for `===` to hold in JavaScript
+                let kind = common(numeric(left, scope), numeric(right, scope));
+                let equal = matches!(operator, BinaryOperator::Equal | BinaryOperator::NotEqual);
+
+                if target == Target::Javascript && kind == Numeric::Unknown && equal {
+                    scope.require(Helper::Equal);
+                    let negation = match operator {
+                        BinaryOperator::NotEqual => "!",
+                        _ => "",
+                    };
+
+                    return Ok(format!(
+                        "{}$eq({}, {})",
+                        negation,
+                        left.compile(target, scope)?,
+                        right.compile(target, scope)?
+                    ));
+                }
+
+                // Python would chain `a < b == c`, so nested comparisons keep their parentheses
+                let precedence = operator.precedence() + 1;
+                let (left, right) = (
+                    wrap(left, precedence, target, scope, kind)?,
+                    wrap(right, precedence, target, scope, kind)?,
+                );
+
+                match (operator, target) {
+                    (BinaryOperator::Equal, Target::Javascript) => {
+                        Ok(format!("{} === {}", left, right))
                     }
+                    (BinaryOperator::NotEqual, Target::Javascript) => {
+                        Ok(format!("{} !== {}", left, right))
+                    }
+                    _ => Ok(format!("{} {} {}", left, operator, right)),
+                }
+            }
+            Expression::Binary {
+                operator: operator @ (BinaryOperator::And | BinaryOperator::Or),
+                left,
+                right,
+                ..
+            } => {
+                let precedence = operator.precedence();
+                let (left, right) = (
+                    wrap(left, precedence, target, scope, numeric(left, scope))?,
+                    wrap(right, precedence + 1, target, scope, numeric(right, scope))?,
+                );
+
+                match (operator, target) {
+                    (BinaryOperator::And, Target::Javascript) => {
+                        Ok(format!("{} && {}", left, right))
+                    }
+                    (BinaryOperator::Or, Target::Javascript) => {
+                        Ok(format!("{} || {}", left, right))
+                    }
+                    _ => Ok(format!("{} {} {}", left, operator, right)),
+                }
+            }
+            Expression::Literal(_) | Expression::Unary { .. } | Expression::Binary { .. } => {
+                arithmetic(self, target, scope, numeric(self, scope))
+            }
+            Expression::Variable(Variable { name, .. }) => Ok(name.to_string()),
+            Expression::Call {
+                callee, arguments, ..
+            } => {
+                let builtin = scope.get_builtin(&callee.name);
+                let name = match builtin {
+                    Some(builtin) => builtin.target_name(target),
+                    None => &callee.name,
+                };
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| match (builtin, target) {
+                        // `console.log` would print `BigInt`s with their `n` suffix
+                        (Some(Builtin { name: "print", .. }), Target::Javascript)
+                            if matches!(
+                                numeric(argument, scope),
+                                Numeric::BigInt | Numeric::Unknown
+                            ) =>
+                        {
+                            Ok(format!("String({})", argument.compile(target, scope)?))
+                        }
+                        _ => argument.compile(target, scope),
+                    })
+                    .collect::<Result<Vec<String>, Diagnostic>>()?;
+
+                Ok(format!("{}({})", name, arguments.join(", ")))
+            }
+            Expression::FormatString { parts, .. } => {
+                let mut output = String::new();
+                // Python `str.format` template, along with its arguments
+                let mut template = String::new();
+                let mut arguments = vec![];
+                let multiline = parts.iter().any(|part| match part {
+                    FormatStringPart::Text(text) => text.contains('\n'),
+                    FormatStringPart::Expression(_) => false,
+                });
+
+                for part in parts {
+                    match (part, target) {
+                        // Strings compile to double quotes, so they can be nested in the expressions
+                        (FormatStringPart::Text(text), Target::Python) => {
+                            let text = escape(text, '\'', multiline)
+                                .replace('{', "{{")
+                                .replace('}', "}}");
+                            output += &text;
+                            template += &text;
+                        }
+                        (FormatStringPart::Text(text), Target::Javascript) => {
+                            output += &escape(text, '`', multiline)
+                        }
+                        (FormatStringPart::Expression(expression), Target::Python) => {
+                            let compiled = expression.compile(target, scope)?;
+                            output += &format!("{{{}}}", compiled);
+                            template += "{}";
+                            arguments.push(compiled);
+                        }
+                        (FormatStringPart::Expression(expression), Target::Javascript) => {
+                            output += &format!("${{{}}}", expression.compile(target, scope)?)
+                        }
+                    }
+                }
+
+                // Before Python 3.12, f-string expressions can't hold backslashes, comments,
+                // line breaks or the quote of the f-string, so those go through `str.format`
+                let inline = arguments
+                    .iter()
+                    .all(|argument| !argument.contains(['\\', '\'', '#', STRING_LINE_BREAK]));
+                let quote = if multiline { "'''" } else { "'" };
+
+                match (target, inline) {
+                    (Target::Python, true) => Ok(format!("f{0}{1}{0}", quote, output)),
+                    (Target::Python, false) => Ok(format!(
+                        "{0}{1}{0}.format({2})",
+                        quote,
+                        template,
+                        arguments.join(", ")
+                    )),
+                    (Target::Javascript, _) => Ok(format!("`{}`", output)),
+                }
+            }
+        }
+    }
+}
+
+/**
+ * Stands for a line break written as is inside a string, so that indenting
+ * a block doesn't change the string. Replaced once the whole node is compiled.
+ */
+const STRING_LINE_BREAK: char = '\u{E000}';
+
+/**
+ * Escapes `text` to be written between two `quote` characters, on either
+ * target. Line breaks are kept when the string spans several lines.
+ */
+fn escape(text: &str, quote: char, multiline: bool) -> String {
+    let mut escaped = String::new();
+    let mut characters = text.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => escaped += "\\\\",
+            '\n' if multiline => escaped.push(STRING_LINE_BREAK),
+            '\n' => escaped += "\\n",
+            STRING_LINE_BREAK => escaped += "\\ue000",
+            '\t' => escaped += "\\t",
+            '\r' => escaped += "\\r",
+            // Would start an interpolation in a template literal
+            '$' if quote == '`' && characters.peek() == Some(&'{') => escaped += "\\$",
+            _ if character == quote => {
+                escaped.push('\\');
+                escaped.push(character);
+            }
+            _ if character.is_control() => escaped += &format!("\\x{:02x}", character as u32),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+/** Largest integer JavaScript numbers hold exactly, `Number.MAX_SAFE_INTEGER` */
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/** Kind of value an expression computes, as far as JavaScript numbers go */
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Numeric {
+    /** Integer no larger than the bound, within the safe range */
+    Integer(f64),
+    /** Integer beyond the safe range of JavaScript numbers */
+    BigInt,
+    Float,
+    /** Number of any kind, such as an argument or the result of a call */
+    Unknown,
+    /** Value that isn't a number, such as a string */
+    Other,
+}
+
+fn numeric(expression: &Expression, scope: &Scope) -> Numeric {
+    match expression {
+        Expression::Literal(Literal::NumberLiteral(value, _)) => {
+            match value.unsigned_abs() > MAX_SAFE_INTEGER as u64 {
+                true => Numeric::BigInt,
+                false => Numeric::Integer(value.unsigned_abs() as f64),
+            }
+        }
+        Expression::Literal(Literal::BigNumberLiteral(..)) => Numeric::BigInt,
+        Expression::Literal(Literal::FloatLiteral(..)) => Numeric::Float,
+        Expression::Literal(Literal::BoolLiteral(..)) => Numeric::Integer(1.0),
+        Expression::Literal(Literal::StringLiteral(..)) | Expression::FormatString { .. } => {
+            Numeric::Other
+        }
+        Expression::Unary {
+            operator: UnaryOperator::Negate,
+            operand,
+            ..
+        } => numeric(operand, scope),
+        Expression::Unary {
+            operator: UnaryOperator::Not,
+            ..
+        } => Numeric::Integer(1.0),
+        Expression::Binary {
+            operator: BinaryOperator::Divide,
+            ..
+        } => Numeric::Float,
+        Expression::Binary {
+            operator: BinaryOperator::And | BinaryOperator::Or,
+            left,
+            right,
+            ..
+        } => match (numeric(left, scope), numeric(right, scope)) {
+            (Numeric::Integer(left), Numeric::Integer(right)) => Numeric::Integer(left.max(right)),
+            (left, right) if left == right => left,
+            _ => Numeric::Unknown,
+        },
+        Expression::Binary {
+            operator,
+            left,
+            right,
+            ..
+        } if operator.is_arithmetic() => {
+            combine(operator, numeric(left, scope), numeric(right, scope))
+        }
+        Expression::Binary { .. } => Numeric::Integer(1.0),
+        Expression::Variable(Variable { name, .. }) => {
+            scope.variable(name).unwrap_or(Numeric::Unknown)
+        }
+        Expression::Call { .. } => Numeric::Unknown,
+    }
+}
+
+/** Kind of value both operands can be compared as */
+fn common(left: Numeric, right: Numeric) -> Numeric {
+    match (left, right) {
+        (Numeric::Other, _) | (_, Numeric::Other) => Numeric::Other,
+        (Numeric::Float, _) | (_, Numeric::Float) => Numeric::Float,
+        (Numeric::Unknown, _) | (_, Numeric::Unknown) => Numeric::Unknown,
+        (Numeric::BigInt, _) | (_, Numeric::BigInt) => Numeric::BigInt,
+        (Numeric::Integer(left), Numeric::Integer(right)) => Numeric::Integer(left.max(right)),
+    }
+}
+
+/** Kind of number the operator computes from operands of both kinds */
+fn combine(operator: &BinaryOperator, left: Numeric, right: Numeric) -> Numeric {
+    let (Numeric::Integer(left), Numeric::Integer(right)) = (left, right) else {
+        return common(left, right);
+    };
+    let bound = match operator {
+        BinaryOperator::Add | BinaryOperator::Subtract => left + right,
+        BinaryOperator::Multiply => left * right,
+        // The quotient is no larger than the dividend, the remainder than the divisor
+        BinaryOperator::FloorDivide => left,
+        _ => right,
+    };
+
+    match bound > MAX_SAFE_INTEGER as f64 {
+        true => Numeric::BigInt,
+        false => Numeric::Integer(bound),
+    }
+}
+
+/** Functions the JavaScript output defines once, when it calls them */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Helper {
+    Integer,
+    Add,
+    Subtract,
+    Multiply,
+    FloorDivide,
+    Modulo,
+    Equal,
+}
+
+impl Helper {
+    fn name(&self) -> &'static str {
+        match self {
+            Helper::Integer => "$int",
+            Helper::Add => "$add",
+            Helper::Subtract => "$sub",
+            Helper::Multiply => "$mul",
+            Helper::FloorDivide => "$floordiv",
+            Helper::Modulo => "$mod",
+            Helper::Equal => "$eq",
+        }
+    }
+
+    fn definition(&self) -> &'static str {
+        match self {
+            // Computes integers with numbers while they are safe, and with `BigInt`s beyond
+            Helper::Integer => {
+                "const $int = (a, b, number, bigint) => {\n\
+                 \x20   const integer = (x) => typeof x === \"bigint\" || typeof x === \"boolean\" || Number.isInteger(x);\n\
+                 \x20   if (!integer(a) || !integer(b)) {\n\
+                 \x20       return typeof a === \"bigint\" || typeof b === \"bigint\" ? number(Number(a), Number(b)) : number(a, b);\n\
+                 \x20   }\n\
+                 \x20   if (typeof a !== \"bigint\" && typeof b !== \"bigint\") {\n\
+                 \x20       const result = number(a, b);\n\
+                 \x20       if (Number.isSafeInteger(result)) {\n\
+                 \x20           return result;\n\
+                 \x20       }\n\
+                 \x20   }\n\
+                 \x20   const result = bigint(BigInt(a), BigInt(b));\n\
+                 \x20   return result >= -9007199254740991n && result <= 9007199254740991n ? Number(result) : result;\n\
+                 };"
+            }
+            Helper::Add => "const $add = (a, b) => $int(a, b, (a, b) => a + b, (a, b) => a + b);",
+            Helper::Subtract => "const $sub = (a, b) => $int(a, b, (a, b) => a - b, (a, b) => a - b);",
+            Helper::Multiply => "const $mul = (a, b) => $int(a, b, (a, b) => a * b, (a, b) => a * b);",
+            Helper::FloorDivide => {
+                "const $floordiv = (a, b) => $int(a, b, (a, b) => Math.floor(a / b), \
+                 (a, b) => a / b - ((a % b !== 0n && (a < 0n) !== (b < 0n)) ? 1n : 0n));"
+            }
+            Helper::Modulo => {
+                "const $mod = (a, b) => $int(a, b, (a, b) => ((a % b) + b) % b, (a, b) => ((a % b) + b) % b);"
+            }
+            // A number and a `BigInt` are only strictly equal when converted
+            Helper::Equal => {
+                "const $eq = (a, b) => (typeof a === \"bigint\" && typeof b === \"number\") || \
+                 (typeof a === \"number\" && typeof b === \"bigint\") ? a == b : a === b;"
+            }
+        }
+    }
+}
+
+/**
+ * Compiles a number or an arithmetic expression, as an operand computed along
+ * with numbers of the `kind`. JavaScript can't mix `BigInt`s with numbers, so
+ * the operand is converted to match, and numbers of unknown kind go through helpers.
+ */
+fn arithmetic(
+    expression: &Expression,
+    target: Target,
+    scope: &Scope,
+    kind: Numeric,
+) -> Result<String, Diagnostic> {
+    let own = numeric(expression, scope);
+
+    if target == Target::Javascript
+        && kind == Numeric::Float
+        && matches!(own, Numeric::BigInt | Numeric::Unknown)
+        && !matches!(expression, Expression::Literal(_))
+    {
+        return Ok(format!(
+            "Number({})",
+            arithmetic(expression, target, scope, own)?
+        ));
+    }
+
+    // Integers computed along with `BigInt`s are computed as `BigInt`s too
+    let bigint = target == Target::Javascript
+        && (kind == Numeric::BigInt || (own == Numeric::BigInt && kind != Numeric::Float));
+    let kind = match bigint {
+        true => Numeric::BigInt,
+        false => own,
+    };
+
+    match expression {
+        Expression::Literal(Literal::NumberLiteral(value, _)) if bigint => {
+            Ok(format!("{}n", value))
+        }
+        Expression::Literal(Literal::BigNumberLiteral(value, _)) if bigint => {
+            Ok(format!("{}n", value))
+        }
+        Expression::Literal(Literal::StringLiteral(..)) => expression.compile(target, scope),
+        Expression::Literal(literal) => literal.compile(target, scope),
+        Expression::Unary {
+            operator: UnaryOperator::Negate,
+            operand,
+            ..
+        } => match operand.as_ref() {
+            // `--x` would be a decrement in JavaScript
+            Expression::Unary { .. } => {
+                Ok(format!("-({})", arithmetic(operand, target, scope, kind)?))
+            }
+            _ => Ok(format!(
+                "-{}",
+                wrap(
+                    operand,
+                    UnaryOperator::Negate.precedence(),
+                    target,
+                    scope,
+                    kind
+                )?
+            )),
+        },
+        Expression::Binary {
+            operator: BinaryOperator::Divide,
+            left,
+            right,
+            ..
+        } if target == Target::Javascript => {
+            let precedence = BinaryOperator::Divide.precedence();
+            let operand = |expression: &Expression, precedence: u8| match numeric(expression, scope)
+            {
+                // Python divides integers of any size into a float
+                Numeric::BigInt => Ok(format!(
+                    "Number({})",
+                    arithmetic(expression, target, scope, Numeric::BigInt)?
                 )),
+                _ => wrap(expression, precedence, target, scope, Numeric::Float),
+            };
+
+            Ok(format!(
+                "{} / {}",
+                operand(left, precedence)?,
+                operand(right, precedence + 1)?
+            ))
+        }
+        Expression::Binary {
+            operator,
+            left,
+            right,
+            ..
+        } if operator.is_arithmetic()
+            && target == Target::Javascript
+            && kind == Numeric::Unknown =>
+        {
+            let helper = match operator {
+                BinaryOperator::Add => Helper::Add,
+                BinaryOperator::Subtract => Helper::Subtract,
+                BinaryOperator::Multiply => Helper::Multiply,
+                BinaryOperator::FloorDivide => Helper::FloorDivide,
+                _ => Helper::Modulo,
+            };
+            scope.require(Helper::Integer);
+            scope.require(helper);
+
+            Ok(format!(
+                "{}({}, {})",
+                helper.name(),
+                arithmetic(left, target, scope, kind)?,
+                arithmetic(right, target, scope, kind)?
+            ))
+        }
+        Expression::Binary {
+            operator,
+            left,
+            right,
+            ..
+        } if operator.is_arithmetic() => {
+            let precedence = operator.precedence();
+            // Left-associative, so `a - (b - c)` keeps its parentheses
+            let (left, right) = (
+                wrap(left, precedence, target, scope, kind)?,
+                wrap(right, precedence + 1, target, scope, kind)?,
+            );
+
+            // JavaScript rounds towards zero and keeps the sign of the dividend,
+            // Python floors and keeps the sign of the divisor
+            match (operator, target) {
+                (BinaryOperator::FloorDivide, Target::Javascript) if bigint => Ok(format!(
+                    "((a, b) => a / b - ((a % b !== 0n && (a < 0n) !== (b < 0n)) ? 1n : 0n))({}, {})",
+                    left, right
+                )),
+                (BinaryOperator::FloorDivide, Target::Javascript) => {
+                    Ok(format!("Math.floor({} / {})", left, right))
+                }
+                (BinaryOperator::Modulo, Target::Javascript) => Ok(format!(
+                    "((a, b) => ((a % b) + b) % b)({}, {})",
+                    left, right
+                )),
+                _ => Ok(format!("{} {} {}", left, operator, right)),
+            }
+        }
+        // Variables, calls and booleans, which JavaScript has to convert
+        _ if bigint && own != Numeric::BigInt => {
+            Ok(format!("BigInt({})", expression.compile(target, scope)?))
+        }
+        _ => expression.compile(target, scope),
+    }
+}
+
+/**
+ * Compiles the expression as an operand computed along with numbers of the
+ * `kind`, in parentheses if it binds looser than `precedence`
+ */
+fn wrap(
+    expression: &Expression,
+    precedence: u8,
+    target: Target,
+    scope: &Scope,
+    kind: Numeric,
+) -> Result<String, Diagnostic> {
+    let compiled = arithmetic(expression, target, scope, kind)?;
+    // Conversions and helpers are calls, which need no parentheses
+    let called = target == Target::Javascript
+        && match numeric(expression, scope) {
+            Numeric::BigInt => kind == Numeric::Float,
+            Numeric::Unknown => {
+                kind == Numeric::Float
+                    || matches!(expression, Expression::Binary { operator, .. } if operator.is_arithmetic())
+            }
+            _ => false,
+        };
+
+    match expression {
+        _ if called => Ok(compiled),
+        Expression::Binary { operator, .. } if operator.precedence() < precedence => {
+            Ok(format!("({})", compiled))
+        }
+        Expression::Unary { operator, .. } if operator.precedence() < precedence => {
+            Ok(format!("({})", compiled))
+        }
+        _ => Ok(compiled),
+    }
+}
+
+impl Compilable for Declaration {
+    fn compile(&self, target: Target, scope: &Scope) -> Result<String, Diagnostic> {
+        let Variable { name, .. } = &self.variable;
+        // The value reads the variable as it was before the assignment
+        let value = self.value.compile(target, scope)?;
+        let declared = scope.declare(name, numeric(&self.value, scope));
+
+        match target {
+            Target::Python => Ok(format!("{} = {}", *name, value)),
+            Target::Javascript if declared => Ok(format!("let {} = {};", *name, value)),
+            Target::Javascript => Ok(format!("{} = {};", *name, value)),
+        }
+    }
+}
+
+impl Compilable for Statement {
+    fn compile(&self, target: Target, scope: &Scope) -> Result<String, Diagnostic> {
+        match self {
+            Statement::Declaration(declaration) => declaration.compile(target, scope),
+            Statement::FunctionDef(function) => function.compile(target, scope),
+            Statement::Expression(expression) => match target {
+                Target::Python => expression.compile(target, scope),
+                Target::Javascript => Ok(format!("{};", expression.compile(target, scope)?)),
             },
-            _ => None,
         }
     }
 }
 
-pub fn compile(node: &dyn Compilable, target: Target) -> Option<String> {
-    node.compile(target)
+impl Compilable for Block {
+    /** Compiles every statement, indented one level */
+    fn compile(&self, target: Target, scope: &Scope) -> Result<String, Diagnostic> {
+        let mut output = String::new();
+
+        for statement in &self.statements {
+            for line in statement.compile(target, scope)?.lines() {
+                output += &format!("    {}\n", line);
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+impl Compilable for FunctionDef {
+    fn compile(&self, target: Target, scope: &Scope) -> Result<String, Diagnostic> {
+        let declared = scope.declare(&self.name.name, Numeric::Other);
+        let parameters: Vec<&str> = self
+            .parameters
+            .iter()
+            .map(|parameter| parameter.variable.name.as_str())
+            .collect();
+
+        // Assigning a name in the body makes a new local variable, like in Python
+        let body_scope = Scope::with_statements(scope, &self.body.statements);
+        for parameter in &parameters {
+            body_scope.declare(parameter, Numeric::Unknown);
+        }
+
+        match target {
+            Target::Python => Ok(format!(
+                "def {}({}):\n{}",
+                self.name.name,
+                parameters.join(", "),
+                self.body.compile(target, &body_scope)?.trim_end()
+            )),
+            Target::Javascript if declared => Ok(format!(
+                "function {}({}) {{\n{}}}",
+                self.name.name,
+                parameters.join(", "),
+                self.body.compile(target, &body_scope)?
+            )),
+            // A function can't be declared over a `let`, but it can be assigned
+            Target::Javascript => Ok(format!(
+                "{} = function {}({}) {{\n{}}};",
+                self.name.name,
+                self.name.name,
+                parameters.join(", "),
+                self.body.compile(target, &body_scope)?
+            )),
+        }
+    }
+}
+
+impl Compilable for Program {
+    /** Compiles every statement, one per line */
+    fn compile(&self, target: Target, scope: &Scope) -> Result<String, Diagnostic> {
+        let scope = Scope::with_statements(scope, &self.statements);
+        let mut output = String::new();
+
+        for statement in &self.statements {
+            output += &statement.compile(target, &scope)?;
+            output.push('\n');
+        }
+
+        // Helpers are defined before the statements that call them
+        match scope.helpers() {
+            helpers if helpers.is_empty() => Ok(output),
+            helpers => Ok(format!("{}\n{}", helpers, output)),
+        }
+    }
+}
+
+pub fn compile(node: &dyn Compilable, target: Target) -> Result<String, Diagnostic> {
+    node.compile(target, &Scope::new())
+        .map(|output| output.replace(STRING_LINE_BREAK, "\n"))
 }
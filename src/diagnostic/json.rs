@@ -0,0 +1,70 @@
+use serde::Serialize;
+
+use crate::{span::Span, translation::Translator};
+
+use super::{Diagnostic, DiagnosticCode, Severity};
+
+#[derive(Serialize)]
+struct JsonLabel {
+    span: Span,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct JsonSuggestion<'a> {
+    span: Span,
+    message: String,
+    replacement: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    code: DiagnosticCode,
+    severity: Severity,
+    message: String,
+    translated_message: String,
+    file: &'a str,
+    span: Span,
+    labels: Vec<JsonLabel>,
+    notes: Vec<String>,
+    suggestions: Vec<JsonSuggestion<'a>>,
+}
+
+/**
+ * Serializes a diagnostic as a single line of JSON. The message is kept
+ * in English so tools can match on it, other texts are translated.
+ */
+pub fn to_json(diagnostic: &Diagnostic, file_name: &str, translator: &Translator) -> String {
+    let json = JsonDiagnostic {
+        code: diagnostic.code,
+        severity: diagnostic.severity,
+        message: diagnostic.message.english(),
+        translated_message: translator.translate(&diagnostic.message),
+        file: file_name,
+        span: diagnostic.span,
+        labels: diagnostic
+            .labels
+            .iter()
+            .map(|label| JsonLabel {
+                span: label.span,
+                message: translator.translate(&label.message),
+            })
+            .collect(),
+        notes: diagnostic
+            .notes
+            .iter()
+            .map(|note| translator.translate(note))
+            .collect(),
+        suggestions: diagnostic
+            .suggestions
+            .iter()
+            .map(|suggestion| JsonSuggestion {
+                span: suggestion.span,
+                message: translator.translate(&suggestion.message),
+                replacement: &suggestion.replacement,
+            })
+            .collect(),
+    };
+
+    serde_json::to_string(&json).unwrap()
+}
@@ -0,0 +1,229 @@
+use std::{env, fmt::Display, io::IsTerminal};
+
+use serde::{Serialize, Serializer};
+use serde_json::json;
+
+use crate::{
+    span::Span,
+    translation::{Message, TranslationKey, Translator},
+};
+
+use self::{json::to_json, render::Renderer};
+
+pub mod json;
+pub mod render;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Note,
+}
+
+impl Severity {
+    pub fn message(&self) -> Message {
+        match &self {
+            Severity::Error => Message::new(TranslationKey::SeverityError, json!({})),
+            Severity::Note => Message::new(TranslationKey::SeverityNote, json!({})),
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self {
+            Severity::Error => write!(f, "error"),
+            Severity::Note => write!(f, "note"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticCode {
+    UnexpectedSymbol,
+    NumberTooLarge,
+    ExpectedLiteral,
+    ExpectedType,
+    InvalidType,
+    ExpectedPipe,
+    ExpectedIdentation,
+    ExpectedVariable,
+    ExpectedToken,
+    MismatchedType,
+    InconsistentDedent,
+    MixedIndentation,
+    ExpectedExpression,
+    WrongArgumentCount,
+    InvalidFormatString,
+    InvalidEscape,
+    UnterminatedString,
+    InvalidNumber,
+    ChainedComparison,
+    CalledBeforeDefinition,
+}
+
+impl From<&DiagnosticCode> for &'static str {
+    fn from(val: &DiagnosticCode) -> Self {
+        match val {
+            DiagnosticCode::UnexpectedSymbol => "E0001",
+            DiagnosticCode::NumberTooLarge => "E0002",
+            DiagnosticCode::ExpectedLiteral => "E0003",
+            DiagnosticCode::ExpectedType => "E0004",
+            DiagnosticCode::InvalidType => "E0005",
+            DiagnosticCode::ExpectedPipe => "E0006",
+            DiagnosticCode::ExpectedIdentation => "E0007",
+            DiagnosticCode::ExpectedVariable => "E0008",
+            DiagnosticCode::ExpectedToken => "E0009",
+            DiagnosticCode::MismatchedType => "E0010",
+            DiagnosticCode::InconsistentDedent => "E0011",
+            DiagnosticCode::MixedIndentation => "E0012",
+            DiagnosticCode::ExpectedExpression => "E0013",
+            DiagnosticCode::WrongArgumentCount => "E0014",
+            DiagnosticCode::InvalidFormatString => "E0015",
+            DiagnosticCode::InvalidEscape => "E0016",
+            DiagnosticCode::UnterminatedString => "E0017",
+            DiagnosticCode::InvalidNumber => "E0018",
+            DiagnosticCode::ChainedComparison => "E0019",
+            DiagnosticCode::CalledBeforeDefinition => "E0020",
+        }
+    }
+}
+
+impl Display for DiagnosticCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code: &'static str = self.into();
+        write!(f, "{}", code)
+    }
+}
+
+impl Serialize for DiagnosticCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.into())
+    }
+}
+
+/** Secondary region of the source related to a diagnostic */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub span: Span,
+    pub message: Message,
+}
+
+/** Replacement of the text in `span` that would fix the diagnostic */
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub span: Span,
+    pub message: Message,
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub message: Message,
+    pub span: Span,
+    pub labels: Vec<Label>,
+    pub notes: Vec<Message>,
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: DiagnosticCode, message: Message, span: Span) -> Self {
+        Diagnostic {
+            severity,
+            code,
+            message,
+            span,
+            labels: vec![],
+            notes: vec![],
+            suggestions: vec![],
+        }
+    }
+
+    pub fn error(code: DiagnosticCode, message: Message, span: Span) -> Self {
+        Diagnostic::new(Severity::Error, code, message, span)
+    }
+
+    pub fn with_label(mut self, span: Span, message: Message) -> Self {
+        self.labels.push(Label { span, message });
+        self
+    }
+
+    pub fn with_note(mut self, note: Message) -> Self {
+        self.notes.push(note);
+        self
+    }
+
+    pub fn with_suggestion(mut self, span: Span, message: Message, replacement: String) -> Self {
+        self.suggestions.push(Suggestion {
+            span,
+            message,
+            replacement,
+        });
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}[{}]: {} (at line {}, column {})",
+            self.severity,
+            self.code,
+            self.message.english(),
+            self.span.start.line,
+            self.span.start.column
+        )
+    }
+}
+
+pub enum ErrorFormat {
+    Human { color: bool },
+    Json,
+}
+
+impl From<String> for ErrorFormat {
+    fn from(string: String) -> Self {
+        match string.as_str() {
+            "json" | "JSON" => ErrorFormat::Json,
+            _ => ErrorFormat::Human {
+                color: std::io::stderr().is_terminal(),
+            },
+        }
+    }
+}
+
+pub fn configure_error_format() -> ErrorFormat {
+    ErrorFormat::from(env::var("GLARE_ERROR_FORMAT").unwrap_or_default())
+}
+
+/** Formats diagnostics for display, either human-readable or as JSON lines */
+pub fn emit(
+    diagnostics: &[Diagnostic],
+    source: &str,
+    file_name: &str,
+    format: &ErrorFormat,
+    translator: &Translator,
+) -> String {
+    match format {
+        ErrorFormat::Human { color } => {
+            let renderer = Renderer::new(source, file_name, *color, translator);
+
+            diagnostics
+                .iter()
+                .map(|diagnostic| renderer.render(diagnostic))
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+        ErrorFormat::Json => diagnostics
+            .iter()
+            .map(|diagnostic| to_json(diagnostic, file_name, translator) + "\n")
+            .collect(),
+    }
+}
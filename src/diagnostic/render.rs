@@ -0,0 +1,208 @@
+use crate::{span::Span, translation::Translator};
+
+use super::{Diagnostic, Severity};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const GREEN: &str = "\x1b[1;32m";
+const BLUE: &str = "\x1b[1;34m";
+
+/** Underline of a span on a single source line (columns are 1-based, end is exclusive) */
+struct Annotation {
+    line: usize,
+    start: usize,
+    end: usize,
+    primary: bool,
+    message: Option<String>,
+}
+
+pub struct Renderer<'a> {
+    source: &'a str,
+    file_name: &'a str,
+    color: bool,
+    translator: &'a Translator,
+}
+
+impl<'a> Renderer<'a> {
+    pub fn new(
+        source: &'a str,
+        file_name: &'a str,
+        color: bool,
+        translator: &'a Translator,
+    ) -> Self {
+        Renderer {
+            source,
+            file_name,
+            color,
+            translator,
+        }
+    }
+
+    /** Renders a diagnostic with the source lines it points at */
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let severity_style = match diagnostic.severity {
+            Severity::Error => RED,
+            Severity::Note => GREEN,
+        };
+
+        let mut annotations = self.annotate(diagnostic.span, true, None);
+        for label in &diagnostic.labels {
+            annotations.extend(self.annotate(
+                label.span,
+                false,
+                Some(self.translator.translate(&label.message)),
+            ));
+        }
+
+        let mut lines: Vec<usize> = annotations.iter().map(|a| a.line).collect();
+        lines.sort_unstable();
+        lines.dedup();
+
+        let gutter = lines.last().copied().unwrap_or(1).to_string().len();
+        let empty_gutter = " ".repeat(gutter);
+
+        let mut output = format!(
+            "{}{}[{}]{}{}: {}{}\n",
+            self.style(severity_style),
+            self.translator.translate(&diagnostic.severity.message()),
+            diagnostic.code,
+            self.style(RESET),
+            self.style(BOLD),
+            self.translator.translate(&diagnostic.message),
+            self.style(RESET),
+        );
+
+        output += &format!(
+            "{}{}-->{} {}:{}:{}\n",
+            empty_gutter,
+            self.style(BLUE),
+            self.style(RESET),
+            self.file_name,
+            diagnostic.span.start.line,
+            diagnostic.span.start.column,
+        );
+        output += &self.gutter(&empty_gutter);
+        output += "\n";
+
+        let mut previous: Option<usize> = None;
+
+        for line in lines {
+            if let Some(previous) = previous {
+                if line > previous + 1 {
+                    output += &format!("{}...{}\n", self.style(BLUE), self.style(RESET));
+                }
+            }
+            previous = Some(line);
+
+            let text = self.line_text(line);
+
+            output += &self.gutter(&format!("{:>width$}", line, width = gutter));
+            output += &format!(" {}\n", text);
+
+            for annotation in annotations.iter().filter(|a| a.line == line) {
+                let padding: String = text
+                    .chars()
+                    .take(annotation.start - 1)
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect();
+                let (marker, style) = if annotation.primary {
+                    ("^", severity_style)
+                } else {
+                    ("-", BLUE)
+                };
+
+                output += &self.gutter(&empty_gutter);
+                output += &format!(
+                    " {}{}{}",
+                    padding,
+                    self.style(style),
+                    marker.repeat(annotation.end - annotation.start),
+                );
+
+                if let Some(message) = &annotation.message {
+                    output += &format!(" {}", message);
+                }
+
+                output += &format!("{}\n", self.style(RESET));
+            }
+        }
+
+        let note_title = self.translator.translate(&Severity::Note.message());
+
+        for note in &diagnostic.notes {
+            output += &format!(
+                "{} {}={} {}{}{}: {}\n",
+                empty_gutter,
+                self.style(BLUE),
+                self.style(RESET),
+                self.style(BOLD),
+                note_title,
+                self.style(RESET),
+                self.translator.translate(note)
+            );
+        }
+
+        output
+    }
+
+    /** Splits a span into one annotation per line it covers (first and last only) */
+    fn annotate(&self, span: Span, primary: bool, message: Option<String>) -> Vec<Annotation> {
+        let (start, end) = (span.start, span.end);
+
+        if start.line == end.line || (end.line == start.line + 1 && end.column == 1) {
+            let end_column = if start.line == end.line {
+                end.column
+            } else {
+                self.line_text(start.line).chars().count() + 1
+            };
+
+            return vec![Annotation {
+                line: start.line,
+                start: start.column,
+                end: end_column.max(start.column + 1),
+                primary,
+                message,
+            }];
+        }
+
+        let first_length = self.line_text(start.line).chars().count() + 1;
+
+        vec![
+            Annotation {
+                line: start.line,
+                start: start.column,
+                end: first_length.max(start.column + 1),
+                primary,
+                message: None,
+            },
+            Annotation {
+                line: end.line,
+                start: 1,
+                end: end.column.max(2),
+                primary,
+                message,
+            },
+        ]
+    }
+
+    fn line_text(&self, line: usize) -> &'a str {
+        self.source
+            .split('\n')
+            .nth(line - 1)
+            .unwrap_or("")
+            .trim_end_matches('\r')
+    }
+
+    fn gutter(&self, content: &str) -> String {
+        format!("{}{} |{}", self.style(BLUE), content, self.style(RESET))
+    }
+
+    fn style(&self, style: &'static str) -> &'static str {
+        if self.color {
+            style
+        } else {
+            ""
+        }
+    }
+}
@@ -0,0 +1,426 @@
+use std::{
+    env, fs,
+    path::Path,
+    process::{self, ExitCode},
+};
+
+use serde_json::json;
+
+use crate::{
+    checker::check,
+    codegen::{compile, Target},
+    diagnostic::{configure_error_format, emit, Diagnostic, ErrorFormat, Severity},
+    parser::{Parser, Program},
+    token::{SpannedToken, Token},
+    tokenizer::Tokenizer,
+    translation::{Message, TranslationKey, Translator},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Build,
+    Check,
+    Run,
+}
+
+/** Compiler stage written out by `--emit`, for debugging the grammar */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emit {
+    Code,
+    Tokens,
+    Ast,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitFormat {
+    Debug,
+    Json,
+}
+
+pub struct Options {
+    pub command: Command,
+    pub input: String,
+    pub target: Target,
+    pub output: Option<String>,
+    pub error_format: ErrorFormat,
+    pub emit: Emit,
+    pub emit_format: EmitFormat,
+}
+
+impl TryFrom<&str> for Command {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "build" => Ok(Command::Build),
+            "check" => Ok(Command::Check),
+            "run" => Ok(Command::Run),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<&str> for Emit {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "code" => Ok(Emit::Code),
+            "tokens" => Ok(Emit::Tokens),
+            "ast" => Ok(Emit::Ast),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<&str> for EmitFormat {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "debug" => Ok(EmitFormat::Debug),
+            "json" => Ok(EmitFormat::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+/** Value of the `--lang <language>` (or `--lang=<language>`) flag */
+pub fn lang_flag(args: &[String]) -> Option<&str> {
+    args.iter().enumerate().find_map(|(index, arg)| {
+        if arg == "--lang" {
+            args.get(index + 1).map(|value| value.as_str())
+        } else {
+            arg.strip_prefix("--lang=")
+        }
+    })
+}
+
+/** Parses the command line, without the program name */
+pub fn parse_args(args: &[String]) -> Result<Options, Message> {
+    let mut positionals = vec![];
+    let mut target = Target::Python;
+    let mut output = None;
+    let mut error_format = configure_error_format();
+    let mut emit = Emit::Code;
+    let mut emit_format = EmitFormat::Debug;
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+
+        let mut value = || match inline.clone().or_else(|| args.next().cloned()) {
+            Some(value) => Ok(value),
+            None => Err(Message::new(
+                TranslationKey::MissingValue,
+                json!({ "option": name }),
+            )),
+        };
+
+        match name {
+            "--target" => {
+                let value = value()?;
+                target = match Target::try_from(value.as_str()) {
+                    Ok(target) => target,
+                    Err(_) => {
+                        return Err(Message::new(
+                            TranslationKey::UnknownTarget,
+                            json!({ "target": value }),
+                        ))
+                    }
+                };
+            }
+            "-o" | "--output" => output = Some(value()?),
+            "--error-format" => error_format = ErrorFormat::from(value()?),
+            "--emit" => {
+                let value = value()?;
+                emit = match Emit::try_from(value.as_str()) {
+                    Ok(emit) => emit,
+                    Err(_) => return Err(invalid_value(name, &value)),
+                };
+            }
+            "--emit-format" => {
+                let value = value()?;
+                emit_format = match EmitFormat::try_from(value.as_str()) {
+                    Ok(emit_format) => emit_format,
+                    Err(_) => return Err(invalid_value(name, &value)),
+                };
+            }
+            // Already handled by `lang_flag`
+            "--lang" => {
+                value()?;
+            }
+            _ if name.starts_with('-') => {
+                return Err(Message::new(
+                    TranslationKey::UnknownOption,
+                    json!({ "option": name }),
+                ))
+            }
+            _ => positionals.push(arg.clone()),
+        }
+    }
+
+    let mut positionals = positionals.into_iter();
+
+    let command = match positionals.next() {
+        Some(command) => match Command::try_from(command.as_str()) {
+            Ok(command) => command,
+            Err(_) => {
+                return Err(Message::new(
+                    TranslationKey::UnknownCommand,
+                    json!({ "command": command }),
+                ))
+            }
+        },
+        None => return Err(Message::new(TranslationKey::Usage, json!({}))),
+    };
+
+    let input = match positionals.next() {
+        Some(input) => input,
+        None => return Err(Message::new(TranslationKey::MissingInput, json!({}))),
+    };
+
+    if let Some(extra) = positionals.next() {
+        return Err(Message::new(
+            TranslationKey::UnknownOption,
+            json!({ "option": extra }),
+        ));
+    }
+
+    Ok(Options {
+        command,
+        input,
+        target,
+        output,
+        error_format,
+        emit,
+        emit_format,
+    })
+}
+
+fn invalid_value(option: &str, value: &str) -> Message {
+    Message::new(
+        TranslationKey::InvalidValue,
+        json!({ "option": option, "value": value }),
+    )
+}
+
+/** Runs the tokenizer over the whole source */
+pub fn tokenize(source: &str) -> Result<Vec<SpannedToken>, Diagnostic> {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut tokens = vec![];
+
+    loop {
+        let token = tokenizer.get_next_token(true)?;
+        let end = matches!(token.token, Token::EOF);
+
+        tokens.push(token);
+
+        if end {
+            return Ok(tokens);
+        }
+    }
+}
+
+/** One token per line, prefixed by its span */
+pub fn dump_tokens(tokens: &[SpannedToken], format: EmitFormat) -> String {
+    tokens
+        .iter()
+        .map(|SpannedToken { token, span }| match format {
+            EmitFormat::Debug => format!(
+                "{}:{}-{}:{} {:?}\n",
+                span.start.line, span.start.column, span.end.line, span.end.column, token
+            ),
+            EmitFormat::Json => format!("{}\n", json!({ "token": token, "span": span })),
+        })
+        .collect()
+}
+
+/** Pretty-printed program, or the program as a single line of JSON */
+pub fn dump_ast(program: &Program, format: EmitFormat) -> String {
+    match format {
+        EmitFormat::Debug => format!("{:#?}\n", program),
+        EmitFormat::Json => format!("{}\n", json!(program)),
+    }
+}
+
+/** Parses and type-checks the source, collecting every diagnostic found */
+pub fn analyze(source: &str) -> (Option<Program>, Vec<Diagnostic>) {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    match parser.parse_program() {
+        Ok(program) => {
+            let diagnostics = check(&program);
+            (Some(program), diagnostics)
+        }
+        Err(diagnostic) => (None, vec![diagnostic]),
+    }
+}
+
+pub fn report(translator: &Translator, message: &Message) {
+    eprintln!(
+        "{}: {}",
+        translator.translate(&Severity::Error.message()),
+        translator.translate(message)
+    );
+}
+
+/** Runs the command, returning the exit code of the process */
+pub fn run(options: &Options, translator: &Translator) -> ExitCode {
+    let source = match fs::read_to_string(&options.input) {
+        Ok(source) => source,
+        Err(error) => {
+            report(
+                translator,
+                &Message::new(
+                    TranslationKey::ReadFailed,
+                    json!({ "path": options.input, "error": error.to_string() }),
+                ),
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.emit != Emit::Code {
+        return run_emit(options, &source, translator);
+    }
+
+    let (program, mut diagnostics) = analyze(&source);
+    let mut code = None;
+
+    if let Some(program) = program {
+        if options.command != Command::Check && !diagnostics.iter().any(|d| d.is_error()) {
+            match compile(&program, options.target) {
+                Ok(generated) => code = Some(generated),
+                Err(diagnostic) => diagnostics.push(diagnostic),
+            }
+        }
+    }
+
+    print_diagnostics(&diagnostics, &source, options, translator);
+
+    let code = match code {
+        Some(code) => code,
+        None if diagnostics.iter().any(|d| d.is_error()) => return ExitCode::FAILURE,
+        None => return ExitCode::SUCCESS,
+    };
+
+    match options.command {
+        Command::Check => ExitCode::SUCCESS,
+        Command::Build => {
+            let output = match &options.output {
+                Some(output) => output.clone(),
+                None => Path::new(&options.input)
+                    .with_extension(options.target.extension())
+                    .display()
+                    .to_string(),
+            };
+
+            match write(&output, &code, translator) {
+                true => ExitCode::SUCCESS,
+                false => ExitCode::FAILURE,
+            }
+        }
+        Command::Run => {
+            let output = env::temp_dir()
+                .join(format!(
+                    "glare-{}.{}",
+                    process::id(),
+                    options.target.extension()
+                ))
+                .display()
+                .to_string();
+
+            if !write(&output, &code, translator) {
+                return ExitCode::FAILURE;
+            }
+
+            let interpreter = options.target.interpreter();
+            let status = process::Command::new(interpreter).arg(&output).status();
+            let _ = fs::remove_file(&output);
+
+            match status {
+                Ok(status) => ExitCode::from(status.code().unwrap_or(1) as u8),
+                Err(error) => {
+                    report(
+                        translator,
+                        &Message::new(
+                            TranslationKey::RunFailed,
+                            json!({ "program": interpreter, "error": error.to_string() }),
+                        ),
+                    );
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+/** Writes the requested stage to the output file, or to stdout */
+fn run_emit(options: &Options, source: &str, translator: &Translator) -> ExitCode {
+    let (dump, diagnostics) = match options.emit {
+        Emit::Tokens => match tokenize(source) {
+            Ok(tokens) => (dump_tokens(&tokens, options.emit_format), vec![]),
+            Err(diagnostic) => (String::new(), vec![diagnostic]),
+        },
+        _ => match analyze(source) {
+            (Some(program), diagnostics) => (dump_ast(&program, options.emit_format), diagnostics),
+            (None, diagnostics) => (String::new(), diagnostics),
+        },
+    };
+
+    print_diagnostics(&diagnostics, source, options, translator);
+
+    if diagnostics.iter().any(|d| d.is_error()) {
+        return ExitCode::FAILURE;
+    }
+
+    match &options.output {
+        Some(output) if !write(output, &dump, translator) => ExitCode::FAILURE,
+        Some(_) => ExitCode::SUCCESS,
+        None => {
+            print!("{}", dump);
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn print_diagnostics(
+    diagnostics: &[Diagnostic],
+    source: &str,
+    options: &Options,
+    translator: &Translator,
+) {
+    if !diagnostics.is_empty() {
+        eprint!(
+            "{}",
+            emit(
+                diagnostics,
+                source,
+                &options.input,
+                &options.error_format,
+                translator
+            )
+        );
+    }
+}
+
+fn write(path: &str, contents: &str, translator: &Translator) -> bool {
+    match fs::write(path, contents) {
+        Ok(_) => true,
+        Err(error) => {
+            report(
+                translator,
+                &Message::new(
+                    TranslationKey::WriteFailed,
+                    json!({ "path": path, "error": error.to_string() }),
+                ),
+            );
+            false
+        }
+    }
+}
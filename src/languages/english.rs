@@ -1,9 +1,98 @@
 use phf::{self, phf_map, Map};
 
 pub static ENGLISH: Map<&'static str, &'static str> = phf_map! {
-    "UnexpectedSymbol" => "Unexpected symbol at index {index}",
-    "UnexpectedSymbolComplete" => "Unexpected symbol: {symbol} (at index {index})",
-    "ExpectedLiteral" => "Expected literal",
-    "ExpectedType" => "Expected valid type",
+    "UnexpectedSymbol" => "Unexpected symbol",
+    "UnexpectedSymbolComplete" => "Unexpected symbol: {symbol}",
+    "NumberTooLarge" => "{value} is too large for type 'Number'",
+    "ExpectedLiteral" => "Expected valid literal value (got {found})",
+    "ExpectedType" => "Expected valid simple type (got {found})",
+    "InvalidType" => "The type {name} is not valid",
+    "ExpectedPipe" => "Expected pipe (got {found})",
+    "ExpectedIdentation" => "Expected identation (got {found})",
+    "ExpectedVariable" => "Expected variable (got {found})",
+    "ExpectedToken" => "Expected {expected} (got {found})",
+    "ExpectedEither" => "Expected {first} or {second} (got {found})",
+    "TypeDeclaredHere" => "type of '{name}' declared here",
+    "VariableDeclaredHere" => "variable declared here",
+    "DeclarationWithoutValue" => "declarations must be initialized with a value",
+    "AddBeforeValue" => "add {token} before the value",
+    "TokenString" => "string '{value}'",
+    "TokenNumber" => "number '{value}'",
+    "TokenType" => "type '{value}'",
+    "TokenPipe" => "pipe",
+    "TokenIndent" => "indent",
+    "TokenLineBreak" => "new line",
+    "TokenEOF" => "EOF",
+    "TokenVariable" => "variable '{value}'",
+    "TokenColon" => ":",
+    "TokenEqual" => "=",
+    "SeverityError" => "error",
+    "SeverityWarning" => "warning",
+    "SeverityNote" => "note",
+    "UntranslatedKeys" => "some messages are not translated to {language} yet: {keys}",
+    "UnknownLanguage" => "unknown language '{language}', using English",
+    "InvalidLocaleFile" => "could not load locale file {path}: {error}",
+    "UnknownLocaleKeys" => "locale file {path} has unknown keys: {keys}",
+    "InvalidLocaleTemplate" => "locale file {path} has an invalid template for {key}",
+    "LocaleNotAnObject" => "locale file {path} must be an object mapping keys to templates",
+    "LocaleTemplateNotString" => "locale file {path} has a template for {key} that is not a string",
+    "MismatchedType" => "Expected value of type {expected} (got {found})",
+    "Usage" => "usage: glare <build|check|run> <file> [--target python|javascript] [-o <output>] [--lang <language>] [--error-format human|json] [--emit code|tokens|ast] [--emit-format debug|json]",
+    "UnknownCommand" => "unknown command '{command}'",
+    "MissingInput" => "no input file given",
+    "MissingValue" => "missing value for {option}",
+    "UnknownTarget" => "unknown target '{target}' (expected python or javascript)",
+    "UnknownOption" => "unknown option '{option}'",
+    "ReadFailed" => "could not read {path}: {error}",
+    "WriteFailed" => "could not write {path}: {error}",
+    "RunFailed" => "could not run {program}: {error}",
+    "InvalidValue" => "invalid value '{value}' for {option}",
+    "TokenDedent" => "end of block",
+    "InconsistentDedent" => "Unindent does not match any outer indentation level",
+    "MixedIndentation" => "Indentation mixes tabs and spaces",
+    "IndentedWithTabs" => "earlier lines are indented with tabs",
+    "IndentedWithSpaces" => "earlier lines are indented with spaces",
+    "TokenDef" => "keyword 'def'",
+    "TokenLeftParen" => "(",
+    "TokenRightParen" => ")",
+    "TokenComma" => ",",
+    "TokenArrow" => "->",
+    "FunctionDeclaredHere" => "function '{name}' declared here",
+    "TokenPlus" => "+",
+    "TokenMinus" => "-",
+    "TokenStar" => "*",
+    "TokenSlash" => "/",
+    "TokenDoubleSlash" => "//",
+    "ExpectedExpression" => "Expected expression (got {found})",
+    "TokenPercent" => "%",
+    "WrongArgumentCount" => "Function '{name}' takes {expected} arguments (got {found})",
+    "TokenFormatString" => "f-string",
+    "TokenRightBrace" => "'}'",
+    "UnclosedInterpolation" => "Unclosed interpolation in f-string",
+    "UnmatchedBrace" => "Unmatched closing brace in f-string",
+    "DoubleBraces" => "write a brace twice to use it as text",
+    "UnknownEscape" => "Unknown escape sequence '{value}'",
+    "SupportedEscapes" => "supported escapes are \\n, \\t, \\r, \\0, \\\\, \\', \\\", \\` and \\u\\{...}",
+    "InvalidUnicodeEscape" => "Invalid unicode escape '{value}'",
+    "UnicodeEscapeFormat" => "write between one and six hexadecimal digits of a valid code point, as in \\u\\{1F600}",
+    "UnterminatedString" => "Unterminated string",
+    "MultilineStrings" => "use triple quotes for a string spanning several lines",
+    "MisplacedUnderscore" => "Misplaced underscore in number '{value}'",
+    "UnderscoreBetweenDigits" => "underscores can only separate digits",
+    "FloatRange" => "'Number' values with a fractional part or an exponent go up to {max}",
+    "TokenBool" => "boolean '{value}'",
+    "TokenAnd" => "and",
+    "TokenOr" => "or",
+    "TokenNot" => "not",
+    "TokenDoubleEqual" => "==",
+    "TokenNotEqual" => "!=",
+    "TokenLess" => "<",
+    "TokenLessEqual" => "<=",
+    "TokenGreater" => ">",
+    "TokenGreaterEqual" => ">=",
+    "ChainedComparison" => "Comparisons can't be chained",
+    "CombineWithAnd" => "combine the comparisons with 'and'",
+    "PreviousComparison" => "already compared here",
+    "CalledBeforeDefinition" => "Function '{name}' is called before it is defined",
     "None" => "None",
 };
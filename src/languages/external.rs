@@ -0,0 +1,114 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use serde_json::{json, Value};
+use tinytemplate::TinyTemplate;
+
+use crate::translation::{Locale, Message, TranslationKey};
+
+/** Locale loaded at runtime from a JSON file mapping translation keys to templates */
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExternalLocale {
+    pub name: String,
+    templates: HashMap<String, &'static str>,
+}
+
+impl Locale for ExternalLocale {
+    fn template(&self, key: &str) -> Option<&'static str> {
+        self.templates.get(key).copied()
+    }
+
+    #[cfg(test)]
+    fn keys(&self) -> Vec<&str> {
+        self.templates.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+/**
+ * Looks for `<locale>.json` and then `<language>.json` in the directory,
+ * so `es_ES.UTF-8` matches either `es_ES.json` or `es.json`.
+ * Locales live for the rest of the program, since templates borrow them.
+ */
+pub fn load_locale(
+    directory: &Path,
+    name: &str,
+) -> Result<Option<&'static ExternalLocale>, Message> {
+    let locale = name.split(['.', '@']).next().unwrap_or(name);
+    let language = locale.split(['_', '-']).next().unwrap_or(locale);
+
+    for candidate in [locale, language] {
+        let path = directory.join(format!("{}.json", candidate));
+
+        if path.is_file() {
+            let locale = parse_locale(&path, candidate)?;
+            return Ok(Some(Box::leak(Box::new(locale))));
+        }
+    }
+
+    Ok(None)
+}
+
+fn parse_locale(path: &Path, name: &str) -> Result<ExternalLocale, Message> {
+    let invalid_file = |error: String| {
+        Message::new(
+            TranslationKey::InvalidLocaleFile,
+            json!({ "path": path.display().to_string(), "error": error }),
+        )
+    };
+
+    let contents = fs::read_to_string(path).map_err(|error| invalid_file(error.to_string()))?;
+    let entries = match serde_json::from_str::<Value>(&contents) {
+        Ok(Value::Object(entries)) => entries,
+        Ok(_) => {
+            return Err(Message::new(
+                TranslationKey::LocaleNotAnObject,
+                json!({ "path": path.display().to_string() }),
+            ))
+        }
+        Err(error) => return Err(invalid_file(error.to_string())),
+    };
+
+    let known: Vec<&'static str> = TranslationKey::ALL.iter().map(|key| key.into()).collect();
+    let unknown: Vec<&str> = entries
+        .keys()
+        .map(|key| key.as_str())
+        .filter(|key| !known.contains(key))
+        .collect();
+
+    if !unknown.is_empty() {
+        return Err(Message::new(
+            TranslationKey::UnknownLocaleKeys,
+            json!({ "path": path.display().to_string(), "keys": unknown.join(", ") }),
+        ));
+    }
+
+    let mut templates = HashMap::new();
+
+    for (key, template) in &entries {
+        let template = match template {
+            Value::String(template) => template.as_str(),
+            _ => {
+                return Err(Message::new(
+                    TranslationKey::LocaleTemplateNotString,
+                    json!({ "path": path.display().to_string(), "key": key }),
+                ))
+            }
+        };
+
+        if TinyTemplate::new().add_template(key, template).is_err() {
+            return Err(Message::new(
+                TranslationKey::InvalidLocaleTemplate,
+                json!({ "path": path.display().to_string(), "key": key }),
+            ));
+        }
+
+        templates.insert(
+            key.clone(),
+            &*Box::leak(template.to_string().into_boxed_str()),
+        );
+    }
+
+    Ok(ExternalLocale {
+        name: name.to_string(),
+        templates,
+    })
+}
@@ -0,0 +1,98 @@
+use phf::{self, phf_map, Map};
+
+pub static JAPANESE: Map<&'static str, &'static str> = phf_map! {
+    "UnexpectedSymbol" => "予期しない記号があります",
+    "UnexpectedSymbolComplete" => "予期しない記号 {symbol} があります",
+    "NumberTooLarge" => "{value} は 'Number' 型には大きすぎます",
+    "ExpectedLiteral" => "有効なリテラル値が必要です ({found} が見つかりました)",
+    "ExpectedType" => "有効な単純型が必要です ({found} が見つかりました)",
+    "InvalidType" => "型 {name} は無効です",
+    "ExpectedPipe" => "パイプが必要です ({found} が見つかりました)",
+    "ExpectedIdentation" => "インデントが必要です ({found} が見つかりました)",
+    "ExpectedVariable" => "変数が必要です ({found} が見つかりました)",
+    "ExpectedToken" => "{expected} が必要です ({found} が見つかりました)",
+    "ExpectedEither" => "{first} または {second} が必要です ({found} が見つかりました)",
+    "TypeDeclaredHere" => "'{name}' の型はここで宣言されています",
+    "VariableDeclaredHere" => "変数はここで宣言されています",
+    "DeclarationWithoutValue" => "宣言は値で初期化する必要があります",
+    "AddBeforeValue" => "値の前に {token} を追加してください",
+    "TokenString" => "文字列 '{value}'",
+    "TokenNumber" => "数値 '{value}'",
+    "TokenType" => "型 '{value}'",
+    "TokenPipe" => "パイプ",
+    "TokenIndent" => "インデント",
+    "TokenLineBreak" => "改行",
+    "TokenEOF" => "ファイルの終わり",
+    "TokenVariable" => "変数 '{value}'",
+    "TokenColon" => ":",
+    "TokenEqual" => "=",
+    "SeverityError" => "エラー",
+    "SeverityWarning" => "警告",
+    "SeverityNote" => "注記",
+    "UntranslatedKeys" => "一部のメッセージはまだ {language} に翻訳されていません: {keys}",
+    "UnknownLanguage" => "不明な言語 '{language}' です。英語を使用します",
+    "InvalidLocaleFile" => "ロケールファイル {path} を読み込めませんでした: {error}",
+    "UnknownLocaleKeys" => "ロケールファイル {path} に不明なキーがあります: {keys}",
+    "InvalidLocaleTemplate" => "ロケールファイル {path} の {key} のテンプレートが無効です",
+    "LocaleNotAnObject" => "ロケールファイル {path} はキーをテンプレートに対応させるオブジェクトでなければなりません",
+    "LocaleTemplateNotString" => "ロケールファイル {path} の {key} のテンプレートが文字列ではありません",
+    "MismatchedType" => "{expected} 型の値が必要です ({found} が見つかりました)",
+    "Usage" => "使い方: glare <build|check|run> <ファイル> [--target python|javascript] [-o <出力>] [--lang <言語>] [--error-format human|json] [--emit code|tokens|ast] [--emit-format debug|json]",
+    "UnknownCommand" => "不明なコマンド '{command}' です",
+    "MissingInput" => "入力ファイルが指定されていません",
+    "MissingValue" => "{option} の値がありません",
+    "UnknownTarget" => "不明なターゲット '{target}' です (python または javascript が必要です)",
+    "UnknownOption" => "不明なオプション '{option}' です",
+    "ReadFailed" => "{path} を読み込めませんでした: {error}",
+    "WriteFailed" => "{path} に書き込めませんでした: {error}",
+    "RunFailed" => "{program} を実行できませんでした: {error}",
+    "InvalidValue" => "{option} の値 '{value}' は無効です",
+    "TokenDedent" => "ブロックの終わり",
+    "InconsistentDedent" => "インデント解除が外側のどのインデントレベルとも一致しません",
+    "MixedIndentation" => "インデントにタブとスペースが混在しています",
+    "IndentedWithTabs" => "前の行はタブでインデントされています",
+    "IndentedWithSpaces" => "前の行はスペースでインデントされています",
+    "TokenDef" => "キーワード 'def'",
+    "TokenLeftParen" => "(",
+    "TokenRightParen" => ")",
+    "TokenComma" => ",",
+    "TokenArrow" => "->",
+    "FunctionDeclaredHere" => "関数 '{name}' はここで宣言されています",
+    "TokenPlus" => "+",
+    "TokenMinus" => "-",
+    "TokenStar" => "*",
+    "TokenSlash" => "/",
+    "TokenDoubleSlash" => "//",
+    "ExpectedExpression" => "式が必要です ({found} が見つかりました)",
+    "TokenPercent" => "%",
+    "WrongArgumentCount" => "関数 '{name}' は {expected} 個の引数を取ります ({found} 個が見つかりました)",
+    "TokenFormatString" => "f文字列",
+    "TokenRightBrace" => "'}'",
+    "UnclosedInterpolation" => "f文字列の埋め込み式が閉じられていません",
+    "UnmatchedBrace" => "f文字列に対応しない閉じ括弧があります",
+    "DoubleBraces" => "括弧を文字として使うには二つ重ねてください",
+    "UnknownEscape" => "不明なエスケープシーケンス '{value}'",
+    "SupportedEscapes" => "使用できるエスケープは \\n, \\t, \\r, \\0, \\\\, \\', \\\", \\` と \\u\\{...} です",
+    "InvalidUnicodeEscape" => "無効なUnicodeエスケープ '{value}'",
+    "UnicodeEscapeFormat" => "\\u\\{1F600} のように、有効なコードポイントを1〜6桁の16進数で書いてください",
+    "UnterminatedString" => "文字列が閉じられていません",
+    "MultilineStrings" => "複数行の文字列には三重引用符を使ってください",
+    "MisplacedUnderscore" => "数値 '{value}' のアンダースコアの位置が不正です",
+    "UnderscoreBetweenDigits" => "アンダースコアは数字の間にのみ置けます",
+    "FloatRange" => "小数部または指数を持つ 'Number' の値は {max} までです",
+    "TokenBool" => "真偽値 '{value}'",
+    "TokenAnd" => "and",
+    "TokenOr" => "or",
+    "TokenNot" => "not",
+    "TokenDoubleEqual" => "==",
+    "TokenNotEqual" => "!=",
+    "TokenLess" => "<",
+    "TokenLessEqual" => "<=",
+    "TokenGreater" => ">",
+    "TokenGreaterEqual" => ">=",
+    "ChainedComparison" => "比較は連結できません",
+    "CombineWithAnd" => "比較は 'and' で組み合わせてください",
+    "PreviousComparison" => "ここで既に比較されています",
+    "CalledBeforeDefinition" => "関数 '{name}' は定義される前に呼び出されています",
+    "None" => "なし",
+};
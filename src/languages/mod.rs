@@ -1,2 +1,4 @@
 pub mod english;
+pub mod external;
+pub mod japanese;
 pub mod portuguese;
@@ -1,9 +1,98 @@
 use phf::{self, phf_map, Map};
 
 pub static PORTUGUESE: Map<&'static str, &'static str> = phf_map! {
-    "UnexpectedSymbol" => "Símbolo inesperado (offset: {index})",
-    "UnexpectedSymbolComplete" => "Símbolo {symbol} inesperado (offset: {index})",
-    "ExpectedLiteral" => "Literal era esperado",
-    "ExpectedType" => "Tipo válido era esperado",
+    "UnexpectedSymbol" => "Símbolo inesperado",
+    "UnexpectedSymbolComplete" => "Símbolo {symbol} inesperado",
+    "NumberTooLarge" => "{value} é grande demais para o tipo 'Number'",
+    "ExpectedLiteral" => "Literal válido era esperado (encontrado: {found})",
+    "ExpectedType" => "Tipo simples válido era esperado (encontrado: {found})",
+    "InvalidType" => "O tipo {name} não é válido",
+    "ExpectedPipe" => "Pipe era esperado (encontrado: {found})",
+    "ExpectedIdentation" => "Identação era esperada (encontrado: {found})",
+    "ExpectedVariable" => "Variável era esperada (encontrado: {found})",
+    "ExpectedToken" => "{expected} era esperado (encontrado: {found})",
+    "ExpectedEither" => "{first} ou {second} era esperado (encontrado: {found})",
+    "TypeDeclaredHere" => "tipo de '{name}' declarado aqui",
+    "VariableDeclaredHere" => "variável declarada aqui",
+    "DeclarationWithoutValue" => "declarações devem ser inicializadas com um valor",
+    "AddBeforeValue" => "adicione {token} antes do valor",
+    "TokenString" => "string '{value}'",
+    "TokenNumber" => "número '{value}'",
+    "TokenType" => "tipo '{value}'",
+    "TokenPipe" => "pipe",
+    "TokenIndent" => "indentação",
+    "TokenLineBreak" => "quebra de linha",
+    "TokenEOF" => "fim do arquivo",
+    "TokenVariable" => "variável '{value}'",
+    "TokenColon" => ":",
+    "TokenEqual" => "=",
+    "SeverityError" => "erro",
+    "SeverityWarning" => "aviso",
+    "SeverityNote" => "nota",
+    "UntranslatedKeys" => "algumas mensagens ainda não foram traduzidas para {language}: {keys}",
+    "UnknownLanguage" => "idioma '{language}' desconhecido, usando inglês",
+    "InvalidLocaleFile" => "não foi possível carregar o arquivo de idioma {path}: {error}",
+    "UnknownLocaleKeys" => "o arquivo de idioma {path} tem chaves desconhecidas: {keys}",
+    "InvalidLocaleTemplate" => "o arquivo de idioma {path} tem um template inválido para {key}",
+    "LocaleNotAnObject" => "o arquivo de idioma {path} deve ser um objeto que associa chaves a templates",
+    "LocaleTemplateNotString" => "o arquivo de idioma {path} tem um template para {key} que não é uma string",
+    "MismatchedType" => "Valor do tipo {expected} era esperado (encontrado: {found})",
+    "Usage" => "uso: glare <build|check|run> <arquivo> [--target python|javascript] [-o <saída>] [--lang <idioma>] [--error-format human|json] [--emit code|tokens|ast] [--emit-format debug|json]",
+    "UnknownCommand" => "comando '{command}' desconhecido",
+    "MissingInput" => "nenhum arquivo de entrada informado",
+    "MissingValue" => "valor ausente para {option}",
+    "UnknownTarget" => "alvo '{target}' desconhecido (esperado python ou javascript)",
+    "UnknownOption" => "opção '{option}' desconhecida",
+    "ReadFailed" => "não foi possível ler {path}: {error}",
+    "WriteFailed" => "não foi possível escrever {path}: {error}",
+    "RunFailed" => "não foi possível executar {program}: {error}",
+    "InvalidValue" => "valor inválido '{value}' para {option}",
+    "TokenDedent" => "fim do bloco",
+    "InconsistentDedent" => "A desindentação não corresponde a nenhum nível de indentação externo",
+    "MixedIndentation" => "A indentação mistura tabs e espaços",
+    "IndentedWithTabs" => "as linhas anteriores são indentadas com tabs",
+    "IndentedWithSpaces" => "as linhas anteriores são indentadas com espaços",
+    "TokenDef" => "palavra-chave 'def'",
+    "TokenLeftParen" => "(",
+    "TokenRightParen" => ")",
+    "TokenComma" => ",",
+    "TokenArrow" => "->",
+    "FunctionDeclaredHere" => "função '{name}' declarada aqui",
+    "TokenPlus" => "+",
+    "TokenMinus" => "-",
+    "TokenStar" => "*",
+    "TokenSlash" => "/",
+    "TokenDoubleSlash" => "//",
+    "ExpectedExpression" => "Expressão era esperada (encontrado: {found})",
+    "TokenPercent" => "%",
+    "WrongArgumentCount" => "A função '{name}' recebe {expected} argumentos (encontrado: {found})",
+    "TokenFormatString" => "f-string",
+    "TokenRightBrace" => "'}'",
+    "UnclosedInterpolation" => "Interpolação não fechada na f-string",
+    "UnmatchedBrace" => "Chave de fechamento sem correspondente na f-string",
+    "DoubleBraces" => "escreva a chave duas vezes para usá-la como texto",
+    "UnknownEscape" => "Sequência de escape desconhecida '{value}'",
+    "SupportedEscapes" => "os escapes suportados são \\n, \\t, \\r, \\0, \\\\, \\', \\\", \\` e \\u\\{...}",
+    "InvalidUnicodeEscape" => "Escape unicode inválido '{value}'",
+    "UnicodeEscapeFormat" => "escreva de um a seis dígitos hexadecimais de um code point válido, como em \\u\\{1F600}",
+    "UnterminatedString" => "String não terminada",
+    "MultilineStrings" => "use aspas triplas para uma string com várias linhas",
+    "MisplacedUnderscore" => "Sublinhado mal posicionado no número '{value}'",
+    "UnderscoreBetweenDigits" => "sublinhados só podem separar dígitos",
+    "FloatRange" => "valores 'Number' com parte fracionária ou expoente vão até {max}",
+    "TokenBool" => "booleano '{value}'",
+    "TokenAnd" => "and",
+    "TokenOr" => "or",
+    "TokenNot" => "not",
+    "TokenDoubleEqual" => "==",
+    "TokenNotEqual" => "!=",
+    "TokenLess" => "<",
+    "TokenLessEqual" => "<=",
+    "TokenGreater" => ">",
+    "TokenGreaterEqual" => ">=",
+    "ChainedComparison" => "Comparações não podem ser encadeadas",
+    "CombineWithAnd" => "combine as comparações com 'and'",
+    "PreviousComparison" => "já comparado aqui",
+    "CalledBeforeDefinition" => "A função '{name}' é chamada antes de ser definida",
     "None" => "Nenhum",
 };
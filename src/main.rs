@@ -1,13 +1,36 @@
-use translation::configure_language;
+use std::{env, process::ExitCode};
 
+use driver::{lang_flag, parse_args, report, run};
+use translation::{configure_language, TranslationKey};
+
+mod builtins;
+mod checker;
 mod codegen;
+mod diagnostic;
+mod driver;
 mod languages;
 mod parser;
+mod span;
+#[cfg(test)]
 mod tests;
 mod token;
 mod tokenizer;
 mod translation;
 
-fn main() {
-    configure_language();
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let translator = configure_language(lang_flag(&args));
+
+    match parse_args(&args) {
+        Ok(options) => run(&options, &translator),
+        Err(message) => {
+            report(&translator, &message);
+
+            if message.key != TranslationKey::Usage {
+                eprintln!("{}", translator.get_template(&TranslationKey::Usage));
+            }
+
+            ExitCode::from(2)
+        }
+    }
 }
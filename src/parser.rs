@@ -1,22 +1,204 @@
-use std::collections::btree_set::Union;
+use std::{fmt::Display, mem};
 
-use crate::{token::Token, tokenizer::Tokenizer};
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    diagnostic::{Diagnostic, DiagnosticCode},
+    span::Span,
+    token::{FormatPart, SpannedToken, Token},
+    tokenizer::Tokenizer,
+    translation::{Message, TranslationKey},
+};
 
 pub struct Parser<'a> {
     tokenizer: &'a mut Tokenizer<'a>,
 }
 
-#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Serialize)]
 pub enum Literal {
-    StringLiteral(String),
-    NumberLiteral(i64),
+    StringLiteral(String, Span),
+    NumberLiteral(i64, Span),
+    FloatLiteral(f64, Span),
+    /** Integer beyond the range of `i64`, in base 10 */
+    BigNumberLiteral(String, Span),
+    BoolLiteral(bool, Span),
+}
+
+impl Literal {
+    pub fn span(&self) -> Span {
+        match self {
+            Literal::StringLiteral(_, span)
+            | Literal::NumberLiteral(_, span)
+            | Literal::FloatLiteral(_, span)
+            | Literal::BigNumberLiteral(_, span)
+            | Literal::BoolLiteral(_, span) => *span,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum UnaryOperator {
+    Negate,
+    Not,
+}
+
+impl UnaryOperator {
+    /** Binding power of the operator, on the same scale as binary operators */
+    pub fn precedence(&self) -> u8 {
+        match self {
+            UnaryOperator::Negate => 7,
+            UnaryOperator::Not => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    FloorDivide,
+    Modulo,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    And,
+    Or,
+}
+
+impl BinaryOperator {
+    /** Binding power, higher binds tighter. Every operator is left-associative */
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Or => 1,
+            BinaryOperator::And => 2,
+            BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::Less
+            | BinaryOperator::LessEqual
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterEqual => 4,
+            BinaryOperator::Add | BinaryOperator::Subtract => 5,
+            BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::FloorDivide
+            | BinaryOperator::Modulo => 6,
+        }
+    }
+
+    pub fn is_comparison(&self) -> bool {
+        self.precedence() == 4
+    }
+
+    /** Whether the operator computes a number from numbers */
+    pub fn is_arithmetic(&self) -> bool {
+        self.precedence() > 4
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BinaryOperator::Add => write!(f, "+"),
+            BinaryOperator::Subtract => write!(f, "-"),
+            BinaryOperator::Multiply => write!(f, "*"),
+            BinaryOperator::Divide => write!(f, "/"),
+            BinaryOperator::FloorDivide => write!(f, "//"),
+            BinaryOperator::Modulo => write!(f, "%"),
+            BinaryOperator::Equal => write!(f, "=="),
+            BinaryOperator::NotEqual => write!(f, "!="),
+            BinaryOperator::Less => write!(f, "<"),
+            BinaryOperator::LessEqual => write!(f, "<="),
+            BinaryOperator::Greater => write!(f, ">"),
+            BinaryOperator::GreaterEqual => write!(f, ">="),
+            BinaryOperator::And => write!(f, "and"),
+            BinaryOperator::Or => write!(f, "or"),
+        }
+    }
+}
+
+impl TryFrom<&Token> for BinaryOperator {
+    type Error = ();
+
+    fn try_from(token: &Token) -> Result<Self, Self::Error> {
+        match token {
+            Token::Plus => Ok(BinaryOperator::Add),
+            Token::Minus => Ok(BinaryOperator::Subtract),
+            Token::Star => Ok(BinaryOperator::Multiply),
+            Token::Slash => Ok(BinaryOperator::Divide),
+            Token::DoubleSlash => Ok(BinaryOperator::FloorDivide),
+            Token::Percent => Ok(BinaryOperator::Modulo),
+            Token::DoubleEqual => Ok(BinaryOperator::Equal),
+            Token::NotEqual => Ok(BinaryOperator::NotEqual),
+            Token::Less => Ok(BinaryOperator::Less),
+            Token::LessEqual => Ok(BinaryOperator::LessEqual),
+            Token::Greater => Ok(BinaryOperator::Greater),
+            Token::GreaterEqual => Ok(BinaryOperator::GreaterEqual),
+            Token::And => Ok(BinaryOperator::And),
+            Token::Or => Ok(BinaryOperator::Or),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub enum Expression {
+    Literal(Literal),
+    Variable(Variable),
+    Unary {
+        operator: UnaryOperator,
+        operand: Box<Expression>,
+        span: Span,
+    },
+    Binary {
+        operator: BinaryOperator,
+        left: Box<Expression>,
+        right: Box<Expression>,
+        span: Span,
+    },
+    Call {
+        callee: Variable,
+        arguments: Vec<Expression>,
+        span: Span,
+    },
+    FormatString {
+        parts: Vec<FormatStringPart>,
+        span: Span,
+    },
+}
+
+/** Piece of an f-string, either text or an interpolated expression */
+#[derive(Debug, Serialize)]
+pub enum FormatStringPart {
+    Text(String),
+    Expression(Expression),
 }
 
-#[derive(Debug, PartialEq)]
+impl Expression {
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Literal(literal) => literal.span(),
+            Expression::Variable(variable) => variable.span,
+            Expression::Unary { span, .. }
+            | Expression::Binary { span, .. }
+            | Expression::Call { span, .. }
+            | Expression::FormatString { span, .. } => *span,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum SimpleType {
     Number,
     Str,
     String,
+    Bool,
     None,
     Empty,
     Never,
@@ -24,25 +206,45 @@ pub enum SimpleType {
 }
 
 impl TryFrom<String> for SimpleType {
-    type Error = String;
+    type Error = Message;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         match value.as_str() {
             "Number" => Ok(SimpleType::Number),
             "str" => Ok(SimpleType::Str),
             "String" => Ok(SimpleType::String),
+            "Bool" => Ok(SimpleType::Bool),
             "None" => Ok(SimpleType::None),
             "()" => Ok(SimpleType::Empty),
             "Never" => Ok(SimpleType::Never),
             "List" => Ok(SimpleType::List),
-            _ => Err(format!("The type {} is not valid", value)),
+            _ => Err(Message::new(
+                TranslationKey::InvalidType,
+                json!({ "name": value }),
+            )),
         }
     }
 }
 
-#[derive(Debug)]
+impl From<&SimpleType> for &'static str {
+    fn from(val: &SimpleType) -> Self {
+        match val {
+            SimpleType::Number => "Number",
+            SimpleType::Str => "str",
+            SimpleType::String => "String",
+            SimpleType::Bool => "Bool",
+            SimpleType::None => "None",
+            SimpleType::Empty => "()",
+            SimpleType::Never => "Never",
+            SimpleType::List => "List",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
 pub struct UnionType {
     pub types: Vec<SimpleType>,
+    pub span: Span,
 }
 
 impl UnionType {
@@ -51,18 +253,65 @@ impl UnionType {
     }
 }
 
-pub struct Identation {
-    pub count: usize,
+/** Indented statements, such as the body of a function */
+#[derive(Debug, Serialize)]
+pub struct Block {
+    pub statements: Vec<Statement>,
+    pub span: Span,
 }
 
+#[derive(Debug, Serialize)]
 pub struct Variable {
     pub name: String,
+    pub span: Span,
 }
 
+#[derive(Debug, Serialize)]
 pub struct Declaration {
     pub variable: Variable,
     pub union_type: Option<UnionType>,
-    pub literal: Literal,
+    pub value: Expression,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize)]
+pub enum Statement {
+    Declaration(Declaration),
+    FunctionDef(FunctionDef),
+    Expression(Expression),
+}
+
+impl Statement {
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::Declaration(declaration) => declaration.span,
+            Statement::FunctionDef(function) => function.span,
+            Statement::Expression(expression) => expression.span(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Parameter {
+    pub variable: Variable,
+    pub union_type: UnionType,
+    pub span: Span,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FunctionDef {
+    pub name: Variable,
+    pub parameters: Vec<Parameter>,
+    pub return_type: Option<UnionType>,
+    pub body: Block,
+    pub span: Span,
+}
+
+/** Root of the AST: every statement of a source file */
+#[derive(Debug, Serialize)]
+pub struct Program {
+    pub statements: Vec<Statement>,
+    pub span: Span,
 }
 
 impl<'a> Parser<'a> {
@@ -75,16 +324,28 @@ impl<'a> Parser<'a> {
      * Literal
      *  : StringLiteral
      *  | NumberLiteral
+     *  | FloatLiteral
+     *  | BigNumberLiteral
+     *  | BoolLiteral
      *  ;
      */
-    pub fn parse_literal(&mut self) -> Result<Literal, String> {
+    pub fn parse_literal(&mut self) -> Result<Literal, Diagnostic> {
         match self.tokenizer.get_next_token(true) {
-            Ok(Some(token)) => match token {
-                Token::String(string) => Ok(Literal::StringLiteral(string)),
-                Token::Number(number) => Ok(Literal::NumberLiteral(number)),
-                _ => Err(format!("Expected valid literal value (got {})", token)),
+            Ok(SpannedToken { token, span }) => match token {
+                Token::String(string) => Ok(Literal::StringLiteral(string, span)),
+                Token::Number(number) => Ok(Literal::NumberLiteral(number, span)),
+                Token::Float(number) => Ok(Literal::FloatLiteral(number, span)),
+                Token::BigNumber(number) => Ok(Literal::BigNumberLiteral(number, span)),
+                Token::Bool(value) => Ok(Literal::BoolLiteral(value, span)),
+                _ => Err(Diagnostic::error(
+                    DiagnosticCode::ExpectedLiteral,
+                    Message::new(
+                        TranslationKey::ExpectedLiteral,
+                        json!({ "found": token.describe() }),
+                    ),
+                    span,
+                )),
             },
-            Ok(None) => Err("Expected valid literal value (got EOF)".to_string()),
             Err(error) => Err(error),
         }
     }
@@ -100,16 +361,27 @@ impl<'a> Parser<'a> {
      *  | List
      * ;
      */
-    pub fn parse_simple_type(&mut self) -> Result<SimpleType, String> {
+    pub fn parse_simple_type(&mut self) -> Result<(SimpleType, Span), Diagnostic> {
         match self.tokenizer.get_next_token(true) {
-            Ok(Some(token)) => match token {
+            Ok(SpannedToken { token, span }) => match token {
                 Token::SimpleType(value) => match SimpleType::try_from(value) {
-                    Ok(result) => Ok(result),
+                    Ok(result) => Ok((result, span)),
+                    Err(error) => Err(Diagnostic::error(DiagnosticCode::InvalidType, error, span)),
+                },
+                // `()` is read as two tokens, since it also opens empty parameter lists
+                Token::LeftParen => match self.expect_token(Token::RightParen) {
+                    Ok(end) => Ok((SimpleType::Empty, span.to(end))),
                     Err(error) => Err(error),
                 },
-                _ => Err(format!("Expected valid simple type (got {})", token)),
+                _ => Err(Diagnostic::error(
+                    DiagnosticCode::ExpectedType,
+                    Message::new(
+                        TranslationKey::ExpectedType,
+                        json!({ "found": token.describe() }),
+                    ),
+                    span,
+                )),
             },
-            Ok(None) => Err("Expected valid literal value (got EOF)".to_string()),
             Err(error) => Err(error),
         }
     }
@@ -119,13 +391,19 @@ impl<'a> Parser<'a> {
      *  : |
      * ;
      */
-    pub fn parse_pipe(&mut self) -> Result<(), String> {
+    pub fn parse_pipe(&mut self) -> Result<Span, Diagnostic> {
         match self.tokenizer.get_next_token(true) {
-            Ok(Some(token)) => match token {
-                Token::Pipe => Ok(()),
-                _ => Err(format!("Expected pipe (got {})", token)),
+            Ok(SpannedToken { token, span }) => match token {
+                Token::Pipe => Ok(span),
+                _ => Err(Diagnostic::error(
+                    DiagnosticCode::ExpectedPipe,
+                    Message::new(
+                        TranslationKey::ExpectedPipe,
+                        json!({ "found": token.describe() }),
+                    ),
+                    span,
+                )),
             },
-            Ok(None) => Err("Expected pipe (got EOF)".to_string()),
             Err(error) => Err(error),
         }
     }
@@ -136,134 +414,656 @@ impl<'a> Parser<'a> {
      *  | SimpleType Pipe UnionType
      * ;
      */
-    pub fn parse_union_type(&mut self) -> Result<UnionType, String> {
+    pub fn parse_union_type(&mut self) -> Result<UnionType, Diagnostic> {
         match self.parse_simple_type() {
-            Ok(simple_type) => {
+            Ok((simple_type, span)) => {
                 let mut types = vec![simple_type];
 
-                if let Ok(Some(Token::Pipe)) = self.tokenizer.get_next_token(false) {
-                    if self.tokenizer.get_next_token(true).is_ok() {
+                if let Ok(SpannedToken {
+                    token: Token::Pipe, ..
+                }) = self.tokenizer.get_next_token(false)
+                {
+                    if self.parse_pipe().is_ok() {
                         let next = self.parse_union_type();
                         return match next {
                             Ok(next) => {
                                 types.extend(next.types);
-                                Ok(UnionType { types })
+                                Ok(UnionType {
+                                    types,
+                                    span: span.to(next.span),
+                                })
                             }
                             Err(error) => Err(error),
                         };
                     }
                 }
 
-                Ok(UnionType { types })
+                Ok(UnionType { types, span })
             }
             Err(error) => Err(error),
         }
     }
 
+    pub fn parse_variable(&mut self) -> Result<Variable, Diagnostic> {
+        match self.tokenizer.get_next_token(true) {
+            Ok(SpannedToken { token, span }) => match token {
+                Token::Variable(name) => Ok(Variable { name, span }),
+                _ => Err(Diagnostic::error(
+                    DiagnosticCode::ExpectedVariable,
+                    Message::new(
+                        TranslationKey::ExpectedVariable,
+                        json!({ "found": token.describe() }),
+                    ),
+                    span,
+                )),
+            },
+            Err(error) => Err(error),
+        }
+    }
+
     /**
-     * Identation
-     *  : Tab Identation
-     * ;
+     * Call
+     *  : Variable ( (Expression (, Expression)* ,?)? )
+     *  ;
+     * Returns the variable itself when it isn't followed by arguments.
      */
-    pub fn parse_identation(&mut self) -> Result<Identation, String> {
-        match self.tokenizer.get_next_token(true) {
-            Ok(Some(token)) => match token {
-                Token::Tab => {
-                    if let Ok(Some(Token::Tab)) = self.tokenizer.get_next_token(false) {
-                        return match self.parse_identation() {
-                            Ok(result) => Ok(Identation {
-                                count: 1 + result.count,
-                            }),
-                            Err(error) => Err(error),
-                        };
+    fn parse_call(&mut self, callee: Variable) -> Result<Expression, Diagnostic> {
+        match self.tokenizer.get_next_token(false)?.token {
+            Token::LeftParen => {
+                self.tokenizer.get_next_token(true)?;
+            }
+            _ => return Ok(Expression::Variable(callee)),
+        }
+
+        let mut arguments = vec![];
+
+        let end = loop {
+            match self.tokenizer.get_next_token(false)? {
+                SpannedToken {
+                    token: Token::RightParen,
+                    span,
+                } => {
+                    self.tokenizer.get_next_token(true)?;
+                    break span;
+                }
+                _ => {
+                    arguments.push(self.parse_expression()?);
+
+                    match self.tokenizer.get_next_token(false)?.token {
+                        Token::Comma => {
+                            self.tokenizer.get_next_token(true)?;
+                        }
+                        _ => break self.expect_token(Token::RightParen)?,
                     }
+                }
+            }
+        };
 
-                    Ok(Identation { count: 1 })
+        Ok(Expression::Call {
+            span: callee.span.to(end),
+            callee,
+            arguments,
+        })
+    }
+
+    /**
+     * FormatString
+     *  : f' (Text | '{' Expression '}')* '
+     *  ;
+     */
+    fn parse_format_string(&mut self) -> Result<Expression, Diagnostic> {
+        let (parts, span) = match self.tokenizer.get_next_token(true)? {
+            SpannedToken {
+                token: Token::FormatString(parts),
+                span,
+            } => (parts, span),
+            SpannedToken { token, span } => {
+                return Err(Diagnostic::error(
+                    DiagnosticCode::ExpectedExpression,
+                    Message::new(
+                        TranslationKey::ExpectedExpression,
+                        json!({ "found": token.describe() }),
+                    ),
+                    span,
+                ))
+            }
+        };
+
+        let mut parsed = vec![];
+
+        for part in parts {
+            match part {
+                FormatPart::Text(text) => parsed.push(FormatStringPart::Text(text)),
+                FormatPart::Expression(source) => {
+                    let mut tokenizer = self.tokenizer.interpolation(source);
+                    let mut parser = Parser::new(&mut tokenizer);
+                    let expression = parser.parse_expression()?;
+
+                    match parser.tokenizer.get_next_token(false)? {
+                        SpannedToken {
+                            token: Token::EOF, ..
+                        } => parsed.push(FormatStringPart::Expression(expression)),
+                        SpannedToken { token, span } => {
+                            return Err(Diagnostic::error(
+                                DiagnosticCode::ExpectedToken,
+                                Message::new(
+                                    TranslationKey::ExpectedToken,
+                                    json!({
+                                        "expected": Message::new(TranslationKey::TokenRightBrace, json!({})),
+                                        "found": token.describe()
+                                    }),
+                                ),
+                                span,
+                            ))
+                        }
+                    }
                 }
-                _ => Err(format!("Expected identation (got {})", token)),
-            },
-            Ok(None) => Err(format!("Expected identation (got {})", Token::EOF)),
-            Err(error) => Err(error),
+            }
         }
+
+        Ok(Expression::FormatString {
+            parts: parsed,
+            span,
+        })
     }
 
-    pub fn parse_variable(&mut self) -> Result<Variable, String> {
-        match self.tokenizer.get_next_token(true) {
-            Ok(Some(token)) => match token {
-                Token::Variable(name) => Ok(Variable { name }),
-                _ => Err(format!("Expected variable (got {})", token)),
+    /**
+     * Primary
+     *  : Literal
+     *  | Variable
+     *  | Call
+     *  | FormatString
+     *  | ( Expression )
+     *  ;
+     */
+    fn parse_primary(&mut self) -> Result<Expression, Diagnostic> {
+        match self.tokenizer.get_next_token(false) {
+            Ok(SpannedToken { token, span }) => match token {
+                Token::String(_)
+                | Token::Number(_)
+                | Token::Float(_)
+                | Token::BigNumber(_)
+                | Token::Bool(_) => self.parse_literal().map(Expression::Literal),
+                Token::Variable(_) => {
+                    let variable = self.parse_variable()?;
+                    self.parse_call(variable)
+                }
+                Token::FormatString(_) => self.parse_format_string(),
+                Token::LeftParen => {
+                    self.tokenizer.get_next_token(true)?;
+                    let expression = self.parse_expression()?;
+                    self.expect_token(Token::RightParen)?;
+                    Ok(expression)
+                }
+                _ => Err(Diagnostic::error(
+                    DiagnosticCode::ExpectedExpression,
+                    Message::new(
+                        TranslationKey::ExpectedExpression,
+                        json!({ "found": token.describe() }),
+                    ),
+                    span,
+                )),
             },
-            Ok(None) => Err(format!("Expected variable (got {})", Token::EOF)),
             Err(error) => Err(error),
         }
     }
 
-    pub fn parse_declaration(&mut self) -> Result<Declaration, String> {
+    /**
+     * Unary
+     *  : - Unary
+     *  | not Expression
+     *  | Primary
+     *  ;
+     */
+    fn parse_unary(&mut self) -> Result<Expression, Diagnostic> {
+        let SpannedToken { token, span } = self.tokenizer.get_next_token(false)?;
+
+        let operator = match token {
+            Token::Minus => UnaryOperator::Negate,
+            Token::Not => UnaryOperator::Not,
+            _ => return self.parse_primary(),
+        };
+
+        self.tokenizer.get_next_token(true)?;
+        // `not` covers the comparisons after it, `-` only the next operand
+        let operand = match operator {
+            UnaryOperator::Negate => self.parse_unary()?,
+            UnaryOperator::Not => self.parse_binary(operator.precedence() + 1)?,
+        };
+
+        Ok(Expression::Unary {
+            span: span.to(operand.span()),
+            operator,
+            operand: Box::new(operand),
+        })
+    }
+
+    /** Parses operators binding at least as tight as `min_precedence` */
+    fn parse_binary(&mut self, min_precedence: u8) -> Result<Expression, Diagnostic> {
+        let left = self.parse_unary()?;
+        self.parse_binary_rest(left, min_precedence)
+    }
+
+    /** Continues a binary expression whose left operand has already been read */
+    fn parse_binary_rest(
+        &mut self,
+        mut left: Expression,
+        min_precedence: u8,
+    ) -> Result<Expression, Diagnostic> {
+        let mut compared = false;
+
+        loop {
+            let operator =
+                match BinaryOperator::try_from(&self.tokenizer.get_next_token(false)?.token) {
+                    Ok(operator) if operator.precedence() >= min_precedence => operator,
+                    _ => return Ok(left),
+                };
+
+            let span = self.tokenizer.get_next_token(true)?.span;
+
+            if operator.is_comparison() {
+                if compared {
+                    return Err(Diagnostic::error(
+                        DiagnosticCode::ChainedComparison,
+                        Message::new(TranslationKey::ChainedComparison, json!({})),
+                        span,
+                    )
+                    .with_label(
+                        left.span(),
+                        Message::new(TranslationKey::PreviousComparison, json!({})),
+                    )
+                    .with_note(Message::new(TranslationKey::CombineWithAnd, json!({}))));
+                }
+                compared = true;
+            }
+
+            let right = self.parse_binary(operator.precedence() + 1)?;
+
+            left = Expression::Binary {
+                span: left.span().to(right.span()),
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+    }
+
+    /**
+     * Expression
+     *  : Expression or Expression
+     *  | Expression and Expression
+     *  | Expression (== | != | < | <= | > | >=) Expression
+     *  | Expression (+ | -) Expression
+     *  | Expression (* | / | // | %) Expression
+     *  | Unary
+     *  ;
+     */
+    pub fn parse_expression(&mut self) -> Result<Expression, Diagnostic> {
+        self.parse_binary(0)
+    }
+
+    #[cfg(test)]
+    pub fn parse_declaration(&mut self) -> Result<Declaration, Diagnostic> {
         match self.parse_variable() {
-            Ok(variable) => match self.tokenizer.get_next_token(false) {
-                Ok(Some(token)) => match token {
-                    Token::Colon => {
-                        if let Ok(Some(Token::Colon)) = self.tokenizer.get_next_token(true) {
-                            return match self.parse_union_type() {
-                                Ok(union_type) => match self.tokenizer.get_next_token(true) {
-                                    Ok(Some(token)) => match token {
-                                        Token::Equal => match self.parse_literal() {
-                                            Ok(literal) => Ok(Declaration {
-                                                variable,
-                                                union_type: Some(union_type),
-                                                literal,
-                                            }),
-                                            Err(error) => Err(error),
-                                        },
-                                        _ => Err(format!(
-                                            "Expected {} (got {})",
-                                            Token::Equal,
-                                            token
-                                        )),
-                                    },
-                                    Ok(None) => Err(format!(
-                                        "Expected {} (got {})",
-                                        Token::Equal,
-                                        Token::EOF
+            Ok(variable) => self.parse_declaration_value(variable),
+            Err(error) => Err(error),
+        }
+    }
+
+    /** Rest of a declaration, once its variable has been read */
+    fn parse_declaration_value(&mut self, variable: Variable) -> Result<Declaration, Diagnostic> {
+        match self.tokenizer.get_next_token(false) {
+            Ok(SpannedToken { token, span }) => match token {
+                Token::Colon => {
+                    // Consume the colon we just peeked
+                    self.tokenizer.get_next_token(true)?;
+
+                    let union_type = self.parse_union_type()?;
+                    let SpannedToken { token, span } = self.tokenizer.get_next_token(true)?;
+                    let expected = Message::new(
+                        TranslationKey::ExpectedToken,
+                        json!({ "expected": Token::Equal.describe(), "found": token.describe() }),
+                    );
+                    let declared_here = Message::new(
+                        TranslationKey::TypeDeclaredHere,
+                        json!({ "name": variable.name }),
+                    );
+
+                    match token {
+                        Token::Equal => {
+                            let value = self.parse_expression()?;
+                            Ok(Declaration {
+                                span: variable.span.to(value.span()),
+                                variable,
+                                union_type: Some(union_type),
+                                value,
+                            })
+                        }
+                        Token::EOF | Token::LineBreak => {
+                            Err(
+                                Diagnostic::error(DiagnosticCode::ExpectedToken, expected, span)
+                                    .with_label(union_type.span, declared_here)
+                                    .with_note(Message::new(
+                                        TranslationKey::DeclarationWithoutValue,
+                                        json!({}),
                                     )),
-                                    Err(error) => Err(error),
-                                },
-                                Err(error) => Err(error),
-                            };
+                            )
                         }
+                        _ => Err(
+                            Diagnostic::error(DiagnosticCode::ExpectedToken, expected, span)
+                                .with_label(union_type.span, declared_here)
+                                .with_suggestion(
+                                    Span::new(span.start, span.start),
+                                    Message::new(
+                                        TranslationKey::AddBeforeValue,
+                                        json!({ "token": Token::Equal.describe() }),
+                                    ),
+                                    format!("{} ", Token::Equal),
+                                ),
+                        ),
+                    }
+                }
+                Token::Equal => {
+                    // Consume the equal sign we just peeked
+                    self.tokenizer.get_next_token(true)?;
 
-                        Err("Unexpected error parsing declaration".to_string())
+                    match self.parse_expression() {
+                        Ok(value) => Ok(Declaration {
+                            span: variable.span.to(value.span()),
+                            variable,
+                            union_type: None,
+                            value,
+                        }),
+                        Err(error) => Err(error),
                     }
-                    Token::Equal => {
-                        if let Ok(Some(Token::Equal)) = self.tokenizer.get_next_token(true) {
-                            return match self.parse_literal() {
-                                Ok(literal) => Ok(Declaration {
-                                    variable,
-                                    union_type: None,
-                                    literal,
-                                }),
-                                Err(error) => Err(error),
-                            };
+                }
+                _ => {
+                    let diagnostic = Diagnostic::error(
+                        DiagnosticCode::ExpectedToken,
+                        Message::new(
+                            TranslationKey::ExpectedEither,
+                            json!({
+                            "first": Token::Colon.describe(),
+                            "second": Token::Equal.describe(),
+                            "found": token.describe()
+                            }),
+                        ),
+                        span,
+                    )
+                    .with_label(
+                        variable.span,
+                        Message::new(TranslationKey::VariableDeclaredHere, json!({})),
+                    );
+
+                    Err(match token {
+                        Token::String(_)
+                        | Token::Number(_)
+                        | Token::Float(_)
+                        | Token::BigNumber(_)
+                        | Token::Bool(_) => diagnostic.with_suggestion(
+                            Span::new(span.start, span.start),
+                            Message::new(
+                                TranslationKey::AddBeforeValue,
+                                json!({ "token": Token::Equal.describe() }),
+                            ),
+                            format!("{} ", Token::Equal),
+                        ),
+                        _ => diagnostic,
+                    })
+                }
+            },
+            Err(error) => Err(error),
+        }
+    }
+
+    /** Consumes the next token, which must be of the same kind as `expected` */
+    fn expect_token(&mut self, expected: Token) -> Result<Span, Diagnostic> {
+        match self.tokenizer.get_next_token(true) {
+            Ok(SpannedToken { token, span }) => {
+                if mem::discriminant(&token) == mem::discriminant(&expected) {
+                    Ok(span)
+                } else {
+                    Err(Diagnostic::error(
+                        DiagnosticCode::ExpectedToken,
+                        Message::new(
+                            TranslationKey::ExpectedToken,
+                            json!({ "expected": expected.describe(), "found": token.describe() }),
+                        ),
+                        span,
+                    ))
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /**
+     * Parameter
+     *  : Variable : UnionType
+     *  ;
+     */
+    pub fn parse_parameter(&mut self) -> Result<Parameter, Diagnostic> {
+        let variable = self.parse_variable()?;
+        self.expect_token(Token::Colon)?;
+        let union_type = self.parse_union_type()?;
+
+        Ok(Parameter {
+            span: variable.span.to(union_type.span),
+            variable,
+            union_type,
+        })
+    }
+
+    /**
+     * FunctionDef
+     *  : def Variable ( (Parameter (, Parameter)* ,?)? ) (-> UnionType)? : LineBreak Block
+     *  ;
+     */
+    pub fn parse_function_def(&mut self) -> Result<FunctionDef, Diagnostic> {
+        let start = self.expect_token(Token::Def)?;
+        let name = self.parse_variable()?;
+        let mut parameters = vec![];
+
+        self.expect_token(Token::LeftParen)?;
+
+        loop {
+            match self.tokenizer.get_next_token(false)?.token {
+                Token::RightParen => {
+                    self.tokenizer.get_next_token(true)?;
+                    break;
+                }
+                _ => {
+                    parameters.push(self.parse_parameter()?);
+
+                    match self.tokenizer.get_next_token(false)?.token {
+                        Token::Comma => {
+                            self.tokenizer.get_next_token(true)?;
+                        }
+                        _ => {
+                            self.expect_token(Token::RightParen)?;
+                            break;
                         }
+                    }
+                }
+            }
+        }
 
-                        Err("Unexpected error parsing declaration".to_string())
+        let return_type = match self.tokenizer.get_next_token(false)?.token {
+            Token::Arrow => {
+                self.tokenizer.get_next_token(true)?;
+                Some(self.parse_union_type()?)
+            }
+            _ => None,
+        };
+
+        let with_label = |diagnostic: Diagnostic| {
+            diagnostic.with_label(
+                name.span,
+                Message::new(
+                    TranslationKey::FunctionDeclaredHere,
+                    json!({ "name": name.name }),
+                ),
+            )
+        };
+
+        self.expect_token(Token::Colon).map_err(with_label)?;
+        self.expect_token(Token::LineBreak).map_err(with_label)?;
+        let body = match self.tokenizer.get_next_token(false)?.token {
+            Token::Indent => self.parse_block()?,
+            // Reports the missing body
+            _ => self.parse_block().map_err(with_label)?,
+        };
+
+        Ok(FunctionDef {
+            span: start.to(body.span),
+            name,
+            parameters,
+            return_type,
+            body,
+        })
+    }
+
+    /**
+     * Statement
+     *  : Declaration
+     *  | FunctionDef
+     *  | Expression
+     *  ;
+     */
+    pub fn parse_statement(&mut self) -> Result<Statement, Diagnostic> {
+        match self.tokenizer.get_next_token(false)?.token {
+            Token::Def => self.parse_function_def().map(Statement::FunctionDef),
+            Token::Variable(_) => {
+                let variable = self.parse_variable()?;
+
+                // Anything that can't continue an expression is reported as a bad declaration
+                match self.tokenizer.get_next_token(false)?.token {
+                    Token::LeftParen | Token::LineBreak | Token::Dedent | Token::EOF => {}
+                    ref token if BinaryOperator::try_from(token).is_ok() => {}
+                    _ => {
+                        return self
+                            .parse_declaration_value(variable)
+                            .map(Statement::Declaration)
                     }
-                    _ => Err(format!(
-                        "Expected {} or {} (got {})",
-                        Token::Colon,
-                        Token::Equal,
-                        token
-                    )),
+                }
+
+                let left = self.parse_call(variable)?;
+                self.parse_binary_rest(left, 0).map(Statement::Expression)
+            }
+            _ => self.parse_expression().map(Statement::Expression),
+        }
+    }
+
+    /**
+     * Statements
+     *  : (Statement (LineBreak | Dedent | EOF))*
+     *  ;
+     * Stops before the Dedent or EOF that ends the list.
+     */
+    fn parse_statements(&mut self) -> Result<Vec<Statement>, Diagnostic> {
+        let mut statements = vec![];
+
+        loop {
+            match self.tokenizer.get_next_token(false) {
+                Ok(SpannedToken { token, .. }) => match token {
+                    Token::LineBreak => {
+                        self.tokenizer.get_next_token(true)?;
+                    }
+                    Token::Dedent | Token::EOF => return Ok(statements),
+                    _ => match self.parse_statement() {
+                        // The block already ended at its Dedent
+                        Ok(statement @ Statement::FunctionDef(_)) => statements.push(statement),
+                        Ok(statement) => match self.tokenizer.get_next_token(false) {
+                            Ok(SpannedToken { token, span }) => match token {
+                                Token::LineBreak => {
+                                    self.tokenizer.get_next_token(true)?;
+                                    statements.push(statement);
+                                }
+                                Token::Dedent | Token::EOF => statements.push(statement),
+                                _ => {
+                                    return Err(Diagnostic::error(
+                                        DiagnosticCode::ExpectedToken,
+                                        Message::new(
+                                            TranslationKey::ExpectedToken,
+                                            json!({
+                                                "expected": Token::LineBreak.describe(),
+                                                "found": token.describe()
+                                            }),
+                                        ),
+                                        span,
+                                    ))
+                                }
+                            },
+                            Err(error) => return Err(error),
+                        },
+                        Err(error) => return Err(error),
+                    },
                 },
-                Ok(None) => Err(format!(
-                    "Expected {} or {} (got {})",
-                    Token::Colon,
-                    Token::Equal,
-                    Token::EOF
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /**
+     * Block
+     *  : Indent Statements Dedent
+     *  ;
+     */
+    pub fn parse_block(&mut self) -> Result<Block, Diagnostic> {
+        match self.tokenizer.get_next_token(true) {
+            Ok(SpannedToken { token, span }) => match token {
+                Token::Indent => {
+                    let statements = self.parse_statements()?;
+
+                    match self.tokenizer.get_next_token(true) {
+                        Ok(SpannedToken {
+                            token: Token::Dedent,
+                            ..
+                        }) => Ok(Block {
+                            span: statements
+                                .iter()
+                                .fold(span, |block, statement| block.to(statement.span())),
+                            statements,
+                        }),
+                        Ok(SpannedToken { token, span }) => Err(Diagnostic::error(
+                            DiagnosticCode::ExpectedToken,
+                            Message::new(
+                                TranslationKey::ExpectedToken,
+                                json!({
+                                    "expected": Token::Dedent.describe(),
+                                    "found": token.describe()
+                                }),
+                            ),
+                            span,
+                        )),
+                        Err(error) => Err(error),
+                    }
+                }
+                _ => Err(Diagnostic::error(
+                    DiagnosticCode::ExpectedIdentation,
+                    Message::new(
+                        TranslationKey::ExpectedIdentation,
+                        json!({ "found": token.describe() }),
+                    ),
+                    span,
                 )),
-                Err(error) => Err(error),
             },
             Err(error) => Err(error),
         }
     }
+
+    /**
+     * Program
+     *  : Statements EOF
+     *  ;
+     */
+    pub fn parse_program(&mut self) -> Result<Program, Diagnostic> {
+        let start = self.tokenizer.position();
+        let statements = self.parse_statements()?;
+
+        Ok(Program {
+            statements,
+            span: Span::new(start, self.tokenizer.position()),
+        })
+    }
 }
@@ -0,0 +1,78 @@
+use serde::Serialize;
+
+/** Location of a single character in the source (line and column are 1-based) */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Position {
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+}
+
+impl Position {
+    /** Returns the position reached after reading `text` from this one */
+    pub fn advance(&self, text: &str) -> Position {
+        let mut position = *self;
+
+        for character in text.chars() {
+            position.offset += character.len_utf8();
+
+            if character == '\n' {
+                position.line += 1;
+                position.column = 1;
+            } else {
+                position.column += 1;
+            }
+        }
+
+        position
+    }
+}
+
+/** Region of the source, from `start` (inclusive) to `end` (exclusive) */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Span { start, end }
+    }
+
+    /** Returns the smallest span covering both `self` and `other` */
+    pub fn to(&self, other: Span) -> Span {
+        Span {
+            start: if other.start.offset < self.start.offset {
+                other.start
+            } else {
+                self.start
+            },
+            end: if other.end.offset > self.end.offset {
+                other.end
+            } else {
+                self.end
+            },
+        }
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.end.offset - self.start.offset
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
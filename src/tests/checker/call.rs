@@ -0,0 +1,64 @@
+use crate::{
+    checker::check,
+    diagnostic::DiagnosticCode,
+    parser::{Parser, Program},
+    tokenizer::Tokenizer,
+    translation::TranslationKey,
+};
+
+fn parse(source: &str) -> Program {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    parser.parse_program().unwrap()
+}
+
+#[test]
+fn argument_count_ok() {
+    assert!(check(&parse("print()\nprint(1, 'a', x)\n")).is_empty());
+    assert!(check(&parse("def f(a: Number):\n    f(a - 1)\nf(2)\n")).is_empty());
+    assert!(check(&parse("unknown(1, 2, 3)\n")).is_empty());
+    assert!(check(&parse(
+        "def f() -> Number:\n    def g():\n        f()\n    g()\n"
+    ))
+    .is_empty());
+    assert!(check(&parse("def f():\n    g()\ndef g():\n    f()\nf()\n")).is_empty());
+}
+
+#[test]
+fn called_before_definition() {
+    let diagnostics = check(&parse(
+        "g(1)\ndef g(a: Number):\n    print(a)\ndef f():\n    h()\n    def h():\n        g(2)\n",
+    ));
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::CalledBeforeDefinition);
+    assert_eq!(diagnostics[0].span.len(), 4);
+    assert_eq!(diagnostics[0].labels[0].span.start.offset, 9);
+    assert_eq!(diagnostics[1].code, DiagnosticCode::CalledBeforeDefinition);
+    assert_eq!(diagnostics[1].message.params["name"], "h");
+}
+
+#[test]
+fn wrong_argument_count() {
+    let diagnostics = check(&parse("def f(a: Number):\n    print(a)\nx = print(f())\n"));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::WrongArgumentCount);
+    assert_eq!(diagnostics[0].message.params["expected"], 1);
+    assert_eq!(diagnostics[0].message.params["found"], 0);
+    assert_eq!(diagnostics[0].span.len(), 3);
+    assert_eq!(
+        diagnostics[0].labels[0].message.key,
+        TranslationKey::FunctionDeclaredHere
+    );
+    assert_eq!(diagnostics[0].labels[0].span.start.offset, 4);
+}
+
+#[test]
+fn call_return_type() {
+    let diagnostics = check(&parse("def f() -> str:\n    print()\nage: Number = f()\n"));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::MismatchedType);
+}
@@ -0,0 +1,44 @@
+use crate::{
+    checker::check,
+    diagnostic::DiagnosticCode,
+    parser::{Declaration, Parser},
+    tokenizer::Tokenizer,
+    translation::TranslationKey,
+};
+
+fn parse(source: &str) -> Declaration {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    parser.parse_declaration().unwrap()
+}
+
+#[test]
+fn compatible_types() {
+    assert!(check(&parse("name: str | Number = \"Glare\"")).is_empty());
+    assert!(check(&parse("age: Number = 42")).is_empty());
+    assert!(check(&parse("untyped = 42")).is_empty());
+    assert!(check(&parse("total: Number = -(2 + 3) * 4")).is_empty());
+    assert!(check(&parse("label: String = 'a' + 'b'")).is_empty());
+    assert!(check(&parse("unknown: str = other * 2")).is_empty());
+    assert!(check(&parse("valid: Bool = not 1 < 2 or False")).is_empty());
+}
+
+#[test]
+fn mismatched_type() {
+    let diagnostics = check(&parse("age: str | String = 42"));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::MismatchedType);
+    assert_eq!(diagnostics[0].message.params["expected"], "str | String");
+    assert_eq!(check(&parse("name: str = 2 * 21"))[0].span.len(), 6);
+    assert_eq!(
+        check(&parse("count: Number = True"))[0].message.params["found"]["params"]["value"],
+        "True"
+    );
+    assert_eq!(check(&parse("flag: Bool = 1 and 2")).len(), 1);
+    assert_eq!(
+        diagnostics[0].labels[0].message.key,
+        TranslationKey::TypeDeclaredHere
+    );
+}
@@ -0,0 +1,2 @@
+mod call;
+mod declaration;
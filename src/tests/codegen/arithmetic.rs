@@ -0,0 +1,46 @@
+use super::{compiled_expression, evaluate};
+use crate::codegen::Target;
+
+#[test]
+fn arithmetic_codegen() {
+    let compiled = |source: &str| compiled_expression(source, Target::Javascript);
+
+    assert_eq!(compiled("7 // 2"), "Math.floor(7 / 2)");
+    assert_eq!(compiled("7 % -2"), "((a, b) => ((a % b) + b) % b)(7, -2)");
+    assert_eq!(compiled("--7"), "-(-7)");
+    // Numbers of unknown kind go through helpers
+    assert_eq!(compiled("a // b"), "$floordiv(a, b)");
+    assert_eq!(compiled("a % -b"), "$mod(a, -b)");
+    assert_eq!(compiled("--a"), "-(-a)");
+}
+
+#[test]
+fn arithmetic_cross_target() {
+    let cases = [
+        "7 // 2",
+        "-7 // 2",
+        "7 // -2",
+        "-7 // -2",
+        "7 % 3",
+        "-7 % 3",
+        "7 % -3",
+        "-7 % -3",
+        "-7 / 2",
+        "7 / -4",
+        "-(7 % 3) - -8 // 3 * 2",
+        "--9 % 4",
+    ];
+
+    // Python adds `.0` to integral floats
+    let evaluate =
+        |source, target| evaluate(source, target).map(|output| output.parse::<f64>().unwrap());
+
+    for source in cases {
+        if let (Some(python), Some(javascript)) = (
+            evaluate(source, Target::Python),
+            evaluate(source, Target::Javascript),
+        ) {
+            assert_eq!(python, javascript, "{}", source);
+        }
+    }
+}
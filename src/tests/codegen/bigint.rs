@@ -0,0 +1,123 @@
+use super::{compiled, compiled_expression, evaluate, run};
+use crate::codegen::Target;
+
+#[test]
+fn bigint_codegen() {
+    let javascript = |source: &str| compiled_expression(source, Target::Javascript);
+
+    assert_eq!(javascript("9007199254740990 + 1"), "9007199254740990 + 1");
+    assert_eq!(javascript("9007199254740991 + 1"), "9007199254740991n + 1n");
+    assert_eq!(javascript("9007199254740992 + 1"), "9007199254740992n + 1n");
+    assert_eq!(javascript("94906266 * 94906266"), "94906266n * 94906266n");
+    assert_eq!(
+        javascript("-(x * 2) + 0x1_0000_0000_0000_0000"),
+        "$add(-$mul(x, 2), 18446744073709551616n)"
+    );
+    assert_eq!(
+        javascript("9007199254740992 / 2"),
+        "Number(9007199254740992n) / 2"
+    );
+    assert_eq!(
+        javascript("9007199254740992 * 0.5"),
+        "9007199254740992 * 0.5"
+    );
+    assert_eq!(
+        compiled_expression("9007199254740992 + 1", Target::Python),
+        "9007199254740992 + 1"
+    );
+}
+
+#[test]
+fn bigint_cross_target() {
+    let cases = [
+        "123456789012345678901234567890 * 3 - 1",
+        "-98765432109876543210 // 7",
+        "98765432109876543210 // -7",
+        "-98765432109876543210 // -7",
+        "98765432109876543210 % -7",
+        "-98765432109876543210 % 7",
+        "--9007199254740993 - 2",
+        "0xffff_ffff_ffff_ffff_ff // 3 + 1",
+        "(9223372036854775807 + 1) * 2",
+        // Safe integers whose result is beyond the safe range
+        "9007199254740991 + 2",
+        "-9007199254740991 - 2",
+        "9007199254740991 * 9007199254740991",
+        "94906266 * 94906266 // 3 % 1000",
+    ];
+
+    for source in cases {
+        if let (Some(python), Some(javascript)) = (
+            evaluate(source, Target::Python),
+            evaluate(source, Target::Javascript),
+        ) {
+            assert_eq!(python, javascript, "{}", source);
+        }
+    }
+
+    // Both print the same float, though Python adds `.0` to integral ones
+    let quotient = |target| evaluate("-90071992547409931 / 10", target);
+    if let (Some(python), Some(javascript)) =
+        (quotient(Target::Python), quotient(Target::Javascript))
+    {
+        assert_eq!(python.parse::<f64>(), javascript.parse::<f64>());
+    }
+}
+
+#[test]
+fn bigint_variables() {
+    let source = "x = 99999999999999999999\n\
+                  y = x + 1\n\
+                  half = x * 0.5\n\
+                  f = 1.5\n\
+                  print(y, -y // 7, y % 7, x / 2 > 1.0)\n\
+                  print(f == 9007199254740993, x == 99999999999999999999, half > f)\n\
+                  print(f'{x}')\n";
+
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "let x = 99999999999999999999n;\nlet y = x + 1n;\nlet half = Number(x) * 0.5;\n\
+         let f = 1.5;\nconsole.log(String(y), String(((a, b) => a / b - \
+         ((a % b !== 0n && (a < 0n) !== (b < 0n)) ? 1n : 0n))(-y, 7n)), \
+         String(((a, b) => ((a % b) + b) % b)(y, 7n)), Number(x) / 2 > 1.0);\n\
+         console.log(f === 9007199254740993, x === 99999999999999999999n, half > f);\n\
+         console.log(`${x}`);\n"
+    );
+
+    for target in [Target::Python, Target::Javascript] {
+        // Python capitalizes `True` and `False`
+        if let Some(output) = run(source, target) {
+            assert_eq!(
+                output.to_lowercase(),
+                "100000000000000000000 -14285714285714285715 2 true\n\
+                 false true true\n\
+                 99999999999999999999\n",
+                "{:?}",
+                target
+            );
+        }
+    }
+}
+
+#[test]
+fn bigint_arguments() {
+    let source = "x = 9007199254740991\n\
+                  def f(a: Number, b: Number):\n    \
+                  print(a + 1, a - b, a * b, a // b, a % b, a == b, a != 7, a < b)\n\
+                  def g(a: Number):\n    \
+                  print(a + 1, a + x, -a * 3)\n\
+                  f(123456789012345678901234567890, 7)\n\
+                  f(9007199254740991, 9007199254740991)\n\
+                  f(-7, 2)\n\
+                  g(123456789012345678901234567890)\n\
+                  g(2)\n\
+                  x = 1\n\
+                  g(2.5)\n";
+
+    // Python capitalizes `True` and `False`, and adds `.0` to integral floats
+    let outputs = [Target::Python, Target::Javascript]
+        .map(|target| run(source, target).map(|output| output.to_lowercase()));
+    if let [Some(python), Some(javascript)] = outputs {
+        assert_eq!(python, javascript);
+    }
+}
@@ -0,0 +1,56 @@
+use super::{compiled_expression as compiled, evaluate};
+use crate::codegen::Target;
+
+#[test]
+fn boolean_codegen() {
+    let cases = [
+        ("True", "True", "true"),
+        ("not a and b", "not a and b", "!a && b"),
+        ("not (a or b)", "not (a or b)", "!(a || b)"),
+        (
+            "a == b or a != c",
+            "a == b or a != c",
+            "$eq(a, b) || !$eq(a, c)",
+        ),
+        ("(a < b) == c", "(a < b) == c", "$eq(a < b, c)"),
+        ("(1 < 2) == True", "(1 < 2) == True", "(1 < 2) === true"),
+        ("-x <= 2 + 1", "-x <= 2 + 1", "-x <= 2 + 1"),
+        ("not -a > b", "not -a > b", "!(-a > b)"),
+        (
+            "x == 9007199254740993",
+            "x == 9007199254740993",
+            "$eq(x, 9007199254740993n)",
+        ),
+    ];
+
+    for (source, python, javascript) in cases {
+        assert_eq!(compiled(source, Target::Python), python);
+        assert_eq!(compiled(source, Target::Javascript), javascript);
+    }
+}
+
+#[test]
+fn boolean_cross_target() {
+    let cases = [
+        "1 < 2 and 2 >= 2",
+        "not 1 == 1.0 or 3 != 3",
+        "not (True and False)",
+        "0 or 5",
+        "2 and 0",
+        "1 + 1 == 2 and not 7 // 2 > 3",
+        "9007199254740993 == 9007199254740992 + 1",
+        "(1 < 2) == True",
+    ];
+
+    // Python capitalizes `True` and `False`
+    let evaluate = |source, target| evaluate(source, target).map(|output| output.to_lowercase());
+
+    for source in cases {
+        if let (Some(python), Some(javascript)) = (
+            evaluate(source, Target::Python),
+            evaluate(source, Target::Javascript),
+        ) {
+            assert_eq!(python, javascript, "{}", source);
+        }
+    }
+}
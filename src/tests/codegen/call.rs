@@ -0,0 +1,72 @@
+use super::{compiled, run};
+use crate::{
+    codegen::{compile, Target},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn call_statements() {
+    let source = "def add(a: Number, b: Number) -> Number:\n    \
+                  print(a + b)\n\
+                  add(1, -2)\n\
+                  print()\n";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    let program = parser.parse_program().unwrap();
+
+    assert_eq!(
+        compile(&program, Target::Python).unwrap(),
+        "def add(a, b):\n    print(a + b)\nadd(1, -2)\nprint()\n"
+    );
+
+    // Arguments can be of any kind, so the sum goes through the helpers defined first
+    let javascript = compile(&program, Target::Javascript).unwrap();
+    let (helpers, statements) = javascript.split_once("\n\n").unwrap();
+    assert!(helpers.starts_with("const $int = "));
+    assert!(
+        helpers.ends_with("\nconst $add = (a, b) => $int(a, b, (a, b) => a + b, (a, b) => a + b);")
+    );
+    assert_eq!(
+        statements,
+        "function add(a, b) {\n    console.log(String($add(a, b)));\n}\nadd(1, -2);\nconsole.log();\n"
+    );
+}
+
+#[test]
+fn shadowed_builtin() {
+    let source = "def f() -> ():\n    \
+                  def print(a: Number) -> ():\n        \
+                  x = a\n    \
+                  print(1)\n\
+                  def g() -> ():\n    \
+                  print(2)\n\
+                  f()\n\
+                  g()\n";
+
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "function f() {\n    function print(a) {\n        let x = a;\n    }\n    print(1);\n}\n\
+         function g() {\n    console.log(2);\n}\nf();\ng();\n"
+    );
+
+    for target in [Target::Python, Target::Javascript] {
+        if let Some(output) = run(source, target) {
+            assert_eq!(output, "2\n", "{:?}", target);
+        }
+    }
+}
+
+#[test]
+fn shadowed_by_variable() {
+    let source = "print(1)\nprint = 5\nprint(2)\n";
+
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "console.log(1);\nlet print = 5;\nprint(2);\n"
+    );
+    assert_eq!(
+        compiled(source, Target::Python),
+        "print(1)\nprint = 5\nprint(2)\n"
+    );
+}
@@ -1,6 +1,8 @@
+use super::{compiled, run};
 use crate::{
     codegen::{compile, Target},
-    parser::{Declaration, Literal, Variable},
+    parser::{Declaration, Expression, Literal, Variable},
+    span::Span,
 };
 
 #[test]
@@ -8,17 +10,77 @@ fn declaration() {
     let declaration = Declaration {
         variable: Variable {
             name: "test".to_string(),
+            span: Span::default(),
         },
         union_type: None,
-        literal: Literal::StringLiteral("Test variable".to_string()),
+        value: Expression::Literal(Literal::StringLiteral(
+            "Test variable".to_string(),
+            Span::default(),
+        )),
+        span: Span::default(),
     };
 
     assert!(match (
         compile(&declaration, Target::Python),
         compile(&declaration, Target::Javascript),
     ) {
-        (Some(python), Some(javascript)) =>
+        (Ok(python), Ok(javascript)) =>
             python == "test = \"Test variable\"" && javascript == "let test = `Test variable`;",
         _ => false,
     });
 }
+
+#[test]
+fn reassignment() {
+    let source = "x = 1\n\
+                  x = x + 1\n\
+                  def f(x: Number) -> ():\n    \
+                  x = 3\n    \
+                  y = x\n    \
+                  y = y + 1\n    \
+                  print(x, y)\n\
+                  def g() -> ():\n    \
+                  x = 5\n\
+                  f(0)\n\
+                  g()\n\
+                  print(x)\n";
+
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "let x = 1;\nx = x + 1;\nfunction f(x) {\n    x = 3;\n    let y = x;\n    y = y + 1;\n    \
+         console.log(x, y);\n}\nfunction g() {\n    let x = 5;\n}\nf(0);\ng();\nconsole.log(x);\n"
+    );
+
+    for target in [Target::Python, Target::Javascript] {
+        if let Some(output) = run(source, target) {
+            assert_eq!(output, "3 4\n2\n", "{:?}", target);
+        }
+    }
+
+    // A function is declared too, so assigning its name only replaces it
+    let source = "def f() -> ():\n    print(1)\nf = 2\nprint(f)\n";
+
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "function f() {\n    console.log(1);\n}\nf = 2;\nconsole.log(f);\n"
+    );
+
+    for target in [Target::Python, Target::Javascript] {
+        if let Some(output) = run(source, target) {
+            assert_eq!(output, "2\n", "{:?}", target);
+        }
+    }
+
+    let source = "g = 1\ndef g() -> ():\n    print(2)\ng()\n";
+
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "let g = 1;\ng = function g() {\n    console.log(2);\n};\ng();\n"
+    );
+
+    for target in [Target::Python, Target::Javascript] {
+        if let Some(output) = run(source, target) {
+            assert_eq!(output, "2\n", "{:?}", target);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    codegen::{compile, Target},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+fn compile_both(source: &str) -> (String, String) {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    let expression = parser.parse_expression().unwrap();
+
+    (
+        compile(&expression, Target::Python).unwrap(),
+        compile(&expression, Target::Javascript).unwrap(),
+    )
+}
+
+#[test]
+fn expression_parentheses() {
+    let (python, javascript) = compile_both("(a + 1) * -(b - c) - (d - (e + 'f'))");
+
+    assert_eq!(python, "(a + 1) * -(b - c) - (d - (e + \"f\"))");
+    assert_eq!(
+        javascript,
+        "$mul($add(a, 1), -$sub(b, c)) - (d - (e + `f`))"
+    );
+
+    let (python, javascript) = compile_both("(1 + 2) * -(3 - 4) - (5 - (6 + 7))");
+
+    assert_eq!(python, "(1 + 2) * -(3 - 4) - (5 - (6 + 7))");
+    assert_eq!(javascript, "(1 + 2) * -(3 - 4) - (5 - (6 + 7))");
+}
+
+#[test]
+fn expression_floor_division() {
+    let (python, javascript) = compile_both("a + b // (c - 1)");
+
+    assert_eq!(python, "a + b // (c - 1)");
+    assert_eq!(javascript, "$add(a, $floordiv(b, $sub(c, 1)))");
+
+    let (python, javascript) = compile_both("1 + 2 // (3 - 1)");
+
+    assert_eq!(python, "1 + 2 // (3 - 1)");
+    assert_eq!(javascript, "1 + Math.floor(2 / (3 - 1))");
+}
@@ -0,0 +1,46 @@
+use super::{compiled, compiled_expression, run};
+use crate::codegen::Target;
+
+#[test]
+fn format_string_codegen() {
+    let source = "f'Total: {a + 1} of {name}'";
+
+    assert_eq!(
+        compiled_expression(source, Target::Python),
+        "f'Total: {a + 1} of {name}'"
+    );
+    assert_eq!(
+        compiled_expression(source, Target::Javascript),
+        "`Total: ${$add(a, 1)} of ${name}`"
+    );
+}
+
+#[test]
+fn format_string_escapes() {
+    let source = "f\"{{it's}} `${{x}}` {print('a')}\"";
+
+    assert_eq!(
+        compiled_expression(source, Target::Python),
+        "f'{{it\\'s}} `${{x}}` {print(\"a\")}'"
+    );
+    assert_eq!(
+        compiled_expression(source, Target::Javascript),
+        "`{it's} \\`\\${x}\\` ${console.log(`a`)}`"
+    );
+}
+
+#[test]
+fn format_string_cross_target() {
+    let source = "print(f\"x{'a\\tb'}y {'#'} {f'{1 + 1}'} {'it\\'s'} {1 + 2}\")\n";
+
+    assert_eq!(
+        compiled(source, Target::Python),
+        "print('x{}y {} {} {} {}'.format(\"a\\tb\", \"#\", f'{1 + 1}', \"it's\", 1 + 2))\n"
+    );
+
+    for target in [Target::Python, Target::Javascript] {
+        if let Some(output) = run(source, target) {
+            assert_eq!(output, "xa\tby # 2 it's 3\n", "{:?}", target);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{
+    codegen::{compile, Target},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn function_def() {
+    let source = "def scale(value: Number, factor: Number) -> Number:\n    \
+                  base = 10\n    \
+                  def inner() -> ():\n        \
+                  unit = 1\n";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    let program = parser.parse_program().unwrap();
+
+    assert_eq!(
+        compile(&program, Target::Python).unwrap(),
+        "def scale(value, factor):\n    base = 10\n    def inner():\n        unit = 1\n"
+    );
+    assert_eq!(
+        compile(&program, Target::Javascript).unwrap(),
+        "function scale(value, factor) {\n    let base = 10;\n    function inner() {\n        \
+         let unit = 1;\n    }\n}\n"
+    );
+}
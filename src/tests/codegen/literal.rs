@@ -1,18 +1,19 @@
 use crate::{
     codegen::{compile, Target},
     parser::Literal,
+    span::Span,
 };
 
 #[test]
 fn literal_string() {
     let literal = "String Literal";
-    let string_literal = Literal::StringLiteral(literal.to_string());
+    let string_literal = Literal::StringLiteral(literal.to_string(), Span::default());
 
     assert!(match (
         compile(&string_literal, Target::Python),
         compile(&string_literal, Target::Javascript),
     ) {
-        (Some(python), Some(javascript)) => python == literal && javascript == literal,
+        (Ok(python), Ok(javascript)) => python == literal && javascript == literal,
         _ => false,
     });
 }
@@ -20,14 +21,28 @@ fn literal_string() {
 #[test]
 fn literal_number() {
     let literal = 42;
-    let number_literal = Literal::NumberLiteral(literal);
+    let number_literal = Literal::NumberLiteral(literal, Span::default());
 
     assert!(match (
         compile(&number_literal, Target::Python),
         compile(&number_literal, Target::Javascript),
     ) {
-        (Some(python), Some(javascript)) =>
+        (Ok(python), Ok(javascript)) =>
             python == format!("{}", literal) && javascript == format!("{}", literal),
         _ => false,
     });
 }
+
+#[test]
+fn literal_float() {
+    for value in [0.1, 3.0, 1e9, 1e100, 2.5e-7, 0.30000000000000004, f64::MAX] {
+        let float_literal = Literal::FloatLiteral(value, Span::default());
+
+        for target in [Target::Python, Target::Javascript] {
+            let compiled = compile(&float_literal, target).unwrap();
+
+            assert!(compiled.contains(['.', 'e']), "{}", compiled);
+            assert_eq!(compiled.parse::<f64>(), Ok(value));
+        }
+    }
+}
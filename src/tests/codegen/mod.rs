@@ -1,2 +1,72 @@
+mod arithmetic;
+mod bigint;
+mod boolean;
+mod call;
 mod declaration;
+mod expression;
+mod format_string;
+mod function;
 mod literal;
+mod multiline;
+mod string;
+
+use std::process::Command;
+
+use crate::{
+    codegen::{compile, Target},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+fn compiled(source: &str, target: Target) -> String {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    compile(&parser.parse_program().unwrap(), target).unwrap()
+}
+
+fn compiled_expression(source: &str, target: Target) -> String {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    compile(&parser.parse_expression().unwrap(), target).unwrap()
+}
+
+/**
+ * Runs code of the target and returns what it printed, or `None` when the
+ * interpreter isn't installed, in which case the caller skips its checks
+ */
+fn execute(code: &str, target: Target) -> Option<String> {
+    let flag = match target {
+        Target::Python => "-c",
+        Target::Javascript => "-e",
+    };
+
+    let output = match Command::new(target.interpreter())
+        .args([flag, code])
+        .output()
+    {
+        Ok(output) => output,
+        Err(error) => {
+            eprintln!("skipped: can't run {}: {}", target.interpreter(), error);
+            return None;
+        }
+    };
+
+    assert!(
+        output.status.success(),
+        "{:?} failed on:\n{}\n{}",
+        target,
+        code,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/** Compiles the program and returns what it printed */
+fn run(source: &str, target: Target) -> Option<String> {
+    execute(&compiled(source, target), target)
+}
+
+/** Prints the expression with `print` and returns the printed line */
+fn evaluate(source: &str, target: Target) -> Option<String> {
+    run(&format!("print({})", source), target).map(|output| output.trim_end().to_string())
+}
@@ -0,0 +1,29 @@
+use super::compiled;
+use crate::codegen::Target;
+
+#[test]
+fn multiline_in_block() {
+    let source =
+        "def f():\n    x = '''first\n  second \"\"\" \u{E000}'''\n    y = f'''{x}\nend'''\n";
+
+    assert_eq!(
+        compiled(source, Target::Python),
+        "def f():\n    x = \"\"\"first\n  second \\\"\\\"\\\" \\ue000\"\"\"\n    \
+         y = f'''{x}\nend'''\n"
+    );
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "function f() {\n    let x = `first\n  second \"\"\" \\ue000`;\n    \
+         let y = `${x}\nend`;\n}\n"
+    );
+}
+
+#[test]
+fn raw_string() {
+    let source = "x = r'C:\\new\\'s' + r\"\"\"a\\\nb\"\"\"";
+
+    assert_eq!(
+        compiled(source, Target::Python),
+        "x = \"C:\\\\new\\\\'s\" + \"\"\"a\\\\\nb\"\"\"\n"
+    );
+}
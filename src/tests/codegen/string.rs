@@ -0,0 +1,28 @@
+use super::{compiled, run};
+use crate::codegen::Target;
+
+#[test]
+fn string_escapes() {
+    let source = r#"x = 'a\"b\'c`${d}\\\t'"#;
+
+    assert_eq!(
+        compiled(source, Target::Python),
+        "x = \"a\\\"b'c`${d}\\\\\\t\"\n"
+    );
+    assert_eq!(
+        compiled(source, Target::Javascript),
+        "let x = `a\"b'c\\`\\${d}\\\\\\t`;\n"
+    );
+}
+
+#[test]
+fn string_round_trip() {
+    let source = r#"print('q\"s\'b`${x}{y}\\ \t\r\0\u{7}\u{E9}\u{1F600}\n' + f'{1}\'${{y}}`\\')"#;
+    let expected = "q\"s'b`${x}{y}\\ \t\r\0\u{7}\u{E9}\u{1F600}\n1'${y}`\\\n";
+
+    for target in [Target::Python, Target::Javascript] {
+        if let Some(output) = run(source, target) {
+            assert_eq!(output, expected, "{:?}", target);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use serde_json::{from_str, Value};
+
+use crate::{
+    diagnostic::{emit, json::to_json, ErrorFormat},
+    parser::Parser,
+    tokenizer::Tokenizer,
+    translation::{Language, Translator},
+};
+
+#[test]
+fn json_fields() {
+    let mut tokenizer = Tokenizer::new("test: Number 42");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) => {
+            let json: Value = from_str(&to_json(
+                &diagnostic,
+                "main.glare",
+                &Translator::new(Language::English),
+            ))
+            .unwrap();
+
+            json["code"] == "E0009"
+                && json["severity"] == "error"
+                && json["message"] == "Expected = (got number '42')"
+                && json["translated_message"].is_string()
+                && json["file"] == "main.glare"
+                && json["span"]["start"]["column"] == 14
+                && json["span"]["end"]["offset"] == 15
+                && json["labels"][0]["message"] == "type of 'test' declared here"
+                && json["suggestions"][0]["replacement"] == "= "
+        }
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn json_lines() {
+    let source = "test = :";
+    let diagnostics: Vec<_> = ["test = :", "test"]
+        .iter()
+        .filter_map(|source| {
+            let mut tokenizer = Tokenizer::new(source);
+            let mut parser = Parser::new(&mut tokenizer);
+            parser.parse_declaration().err()
+        })
+        .collect();
+
+    let output = emit(
+        &diagnostics,
+        source,
+        "main.glare",
+        &ErrorFormat::Json,
+        &Translator::new(Language::English),
+    );
+
+    assert!(
+        output.lines().count() == 2
+            && output
+                .lines()
+                .all(|line| from_str::<Value>(line).is_ok_and(|json| json.is_object()))
+    );
+}
@@ -0,0 +1,3 @@
+mod json;
+mod parse_error;
+mod render;
@@ -0,0 +1,49 @@
+use crate::{
+    diagnostic::{DiagnosticCode, Severity},
+    parser::Parser,
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn expected_token_diagnostic() {
+    let mut tokenizer = Tokenizer::new("test: Number 42");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) =>
+            diagnostic.severity == Severity::Error
+                && diagnostic.code == DiagnosticCode::ExpectedToken
+                && diagnostic.span.start.column == 14
+                && diagnostic.labels.len() == 1
+                && diagnostic.labels[0].span.start.column == 7,
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn number_too_large_diagnostic() {
+    let mut tokenizer = Tokenizer::new("1e999999999999999999");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_literal() {
+        Err(diagnostic) =>
+            diagnostic.code == DiagnosticCode::NumberTooLarge
+                && diagnostic.span.len() == 20
+                && !diagnostic.notes.is_empty(),
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn unexpected_eof_diagnostic() {
+    let mut tokenizer = Tokenizer::new("test =  ");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) =>
+            diagnostic.code == DiagnosticCode::ExpectedExpression
+                && diagnostic.span.is_empty()
+                && diagnostic.span.start.offset == 8,
+        Ok(_) => false,
+    })
+}
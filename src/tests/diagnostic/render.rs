@@ -0,0 +1,86 @@
+use crate::{
+    diagnostic::render::Renderer,
+    parser::Parser,
+    tokenizer::Tokenizer,
+    translation::{Language, Translator},
+};
+
+#[test]
+fn render_labels() {
+    let source = "# Declaration\ntest: Number 42";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) =>
+            Renderer::new(
+                source,
+                "main.glare",
+                false,
+                &Translator::new(Language::English)
+            )
+            .render(&diagnostic)
+                == [
+                    "error[E0009]: Expected = (got number '42')",
+                    " --> main.glare:2:14",
+                    "  |",
+                    "2 | test: Number 42",
+                    "  |              ^^",
+                    "  |       ------ type of 'test' declared here",
+                    "",
+                ]
+                .join("\n"),
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn render_eof_with_note() {
+    let source = "test: Number";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) =>
+            Renderer::new(
+                source,
+                "main.glare",
+                false,
+                &Translator::new(Language::English)
+            )
+            .render(&diagnostic)
+                == [
+                    "error[E0009]: Expected = (got EOF)",
+                    " --> main.glare:1:13",
+                    "  |",
+                    "1 | test: Number",
+                    "  |             ^",
+                    "  |       ------ type of 'test' declared here",
+                    "  = note: declarations must be initialized with a value",
+                    "",
+                ]
+                .join("\n"),
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn render_color() {
+    let source = "test = :";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) => {
+            let rendered = Renderer::new(
+                source,
+                "main.glare",
+                true,
+                &Translator::new(Language::English),
+            )
+            .render(&diagnostic);
+            rendered.starts_with("\x1b[1;31merror[E0013]") && rendered.contains("\x1b[0m")
+        }
+        Ok(_) => false,
+    })
+}
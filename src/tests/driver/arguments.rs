@@ -0,0 +1,49 @@
+use crate::{
+    codegen::Target,
+    driver::{lang_flag, parse_args, Command, Emit},
+    translation::TranslationKey,
+};
+
+fn args(line: &str) -> Vec<String> {
+    line.split_whitespace().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn build_arguments() {
+    let options = parse_args(&args("build main.glare --target=js -o out.js --lang pt")).unwrap();
+
+    assert_eq!(options.command, Command::Build);
+    assert_eq!(options.input, "main.glare");
+    assert_eq!(options.target, Target::Javascript);
+    assert_eq!(options.output.as_deref(), Some("out.js"));
+    assert_eq!(options.emit, Emit::Code);
+    assert_eq!(lang_flag(&args("check main.glare --lang=pt")), Some("pt"));
+}
+
+#[test]
+fn invalid_arguments() {
+    let error = |line: &str| parse_args(&args(line)).err().map(|message| message.key);
+
+    assert_eq!(error(""), Some(TranslationKey::Usage));
+    assert_eq!(
+        error("compile main.glare"),
+        Some(TranslationKey::UnknownCommand)
+    );
+    assert_eq!(error("run"), Some(TranslationKey::MissingInput));
+    assert_eq!(
+        error("build main.glare --target"),
+        Some(TranslationKey::MissingValue)
+    );
+    assert_eq!(
+        error("build main.glare --target ruby"),
+        Some(TranslationKey::UnknownTarget)
+    );
+    assert_eq!(
+        error("build main.glare --verbose"),
+        Some(TranslationKey::UnknownOption)
+    );
+    assert_eq!(
+        error("build main.glare --emit=bytecode"),
+        Some(TranslationKey::InvalidValue)
+    );
+}
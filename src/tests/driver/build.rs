@@ -0,0 +1,40 @@
+use crate::{
+    codegen::{compile, Target},
+    diagnostic::DiagnosticCode,
+    driver::analyze,
+};
+
+#[test]
+fn build_program() {
+    let (program, diagnostics) = analyze("name: str = \"Glare\"\nage: Number = 42\n");
+    let program = program.unwrap();
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+        compile(&program, Target::Python).unwrap(),
+        "name = \"Glare\"\nage = 42\n"
+    );
+    assert_eq!(
+        compile(&program, Target::Javascript).unwrap(),
+        "let name = `Glare`;\nlet age = 42;\n"
+    );
+}
+
+#[test]
+fn check_program() {
+    let codes = |source: &str| -> Vec<DiagnosticCode> {
+        analyze(source).1.iter().map(|d| d.code).collect()
+    };
+
+    assert_eq!(
+        codes("age: str = 42\nname: Number = 7\nlabel: String = 7"),
+        vec![
+            DiagnosticCode::MismatchedType,
+            DiagnosticCode::MismatchedType
+        ]
+    );
+    assert_eq!(
+        codes("age: str = 42\nname = "),
+        vec![DiagnosticCode::ExpectedExpression]
+    );
+}
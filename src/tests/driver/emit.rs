@@ -0,0 +1,33 @@
+use crate::driver::{analyze, dump_ast, dump_tokens, tokenize, EmitFormat};
+
+#[test]
+fn emit_tokens() {
+    let tokens = tokenize("age: Number = 42").unwrap();
+
+    assert_eq!(
+        dump_tokens(&tokens, EmitFormat::Debug),
+        "1:1-1:4 Variable(\"age\")\n\
+         1:4-1:5 Colon\n\
+         1:6-1:12 SimpleType(\"Number\")\n\
+         1:13-1:14 Equal\n\
+         1:15-1:17 Number(42)\n\
+         1:17-1:17 EOF\n"
+    );
+    assert!(dump_tokens(&tokens, EmitFormat::Json).starts_with(
+        "{\"span\":{\"end\":{\"column\":4,\"line\":1,\"offset\":3},\
+         \"start\":{\"column\":1,\"line\":1,\"offset\":0}},\"token\":{\"Variable\":\"age\"}}\n"
+    ));
+}
+
+#[test]
+fn emit_ast() {
+    let program = analyze("age: Number = 42").0.unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(dump_ast(&program, EmitFormat::Json).trim()).unwrap();
+    let declaration = &json["statements"][0]["Declaration"];
+
+    assert_eq!(declaration["variable"]["name"], "age");
+    assert_eq!(declaration["union_type"]["types"][0], "Number");
+    assert_eq!(declaration["value"]["Literal"]["NumberLiteral"][0], 42);
+    assert!(dump_ast(&program, EmitFormat::Debug).contains("NumberLiteral(\n"));
+}
@@ -0,0 +1,3 @@
+mod arguments;
+mod build;
+mod emit;
@@ -1,2 +1,7 @@
+mod checker;
+mod codegen;
+mod diagnostic;
+mod driver;
 mod parser;
-mod codegen;
\ No newline at end of file
+mod tokenizer;
+mod translation;
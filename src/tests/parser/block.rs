@@ -0,0 +1,40 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    parser::{Parser, Statement},
+    tokenizer::Tokenizer,
+};
+
+fn names(statements: &[Statement]) -> Vec<&str> {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Declaration(declaration) => declaration.variable.name.as_str(),
+            Statement::FunctionDef(function) => function.name.name.as_str(),
+            Statement::Expression(_) => "_",
+        })
+        .collect()
+}
+
+#[test]
+fn block_ok() {
+    let mut tokenizer = Tokenizer::new("    a = 1\n\n    # Comment\n    b = 2\nc = 3");
+    let mut parser = Parser::new(&mut tokenizer);
+    let block = parser.parse_block().unwrap();
+
+    assert_eq!(names(&block.statements), vec!["a", "b"]);
+    assert_eq!(
+        names(&parser.parse_program().unwrap().statements),
+        vec!["c"]
+    );
+}
+
+#[test]
+fn block_err() {
+    let mut tokenizer = Tokenizer::new("a = 1");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_block() {
+        Err(diagnostic) => diagnostic.code == DiagnosticCode::ExpectedIdentation,
+        Ok(_) => false,
+    });
+}
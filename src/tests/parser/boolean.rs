@@ -0,0 +1,48 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    parser::{Expression, Parser},
+    tokenizer::Tokenizer,
+};
+
+fn parse(source: &str) -> Result<Expression, DiagnosticCode> {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    parser
+        .parse_expression()
+        .map_err(|diagnostic| diagnostic.code)
+}
+
+#[test]
+fn boolean_precedence() {
+    let grouped = |source: &str| match parse(source) {
+        Ok(Expression::Binary {
+            operator,
+            left,
+            right,
+            ..
+        }) => format!("{} {} {}", left.span().len(), operator, right.span().len()),
+        Ok(Expression::Unary { operand, .. }) => format!("not {}", operand.span().len()),
+        _ => String::new(),
+    };
+
+    assert_eq!(grouped("a or b and c"), "1 or 7");
+    assert_eq!(grouped("a and b or c"), "7 or 1");
+    assert_eq!(grouped("a + 1 < b * 2 and c"), "13 and 1");
+    assert_eq!(grouped("not a == b"), "not 6");
+    assert_eq!(grouped("not a and b"), "5 and 1");
+    assert_eq!(grouped("True != -1"), "4 != 2");
+}
+
+#[test]
+fn chained_comparison() {
+    let mut tokenizer = Tokenizer::new("a < b < c");
+    let mut parser = Parser::new(&mut tokenizer);
+    let diagnostic = parser.parse_expression().unwrap_err();
+
+    assert_eq!(diagnostic.code, DiagnosticCode::ChainedComparison);
+    assert_eq!(diagnostic.span.start.offset, 6);
+    assert_eq!(diagnostic.labels[0].span.len(), 5);
+    assert!(parse("(a < b) == c").is_ok());
+    assert!(parse("a < b and b < c").is_ok());
+}
@@ -0,0 +1,56 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    parser::{Expression, Parser, Statement},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn call_arguments() {
+    let mut tokenizer = Tokenizer::new("scale(x + 1, 2, f())");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_expression() {
+        Ok(Expression::Call {
+            callee,
+            arguments,
+            span,
+        }) =>
+            callee.name == "scale"
+                && arguments.len() == 3
+                && matches!(&arguments[2], Expression::Call { arguments, .. } if arguments.is_empty())
+                && span.len() == 20,
+        _ => false,
+    })
+}
+
+#[test]
+fn expression_statements() {
+    let mut tokenizer = Tokenizer::new("print('Glare')\ntotal\nx = f(1) * 2\n");
+    let mut parser = Parser::new(&mut tokenizer);
+    let program = parser.parse_program().unwrap();
+
+    assert!(matches!(
+        &program.statements[..],
+        [
+            Statement::Expression(Expression::Call { .. }),
+            Statement::Expression(Expression::Variable(_)),
+            Statement::Declaration(_),
+        ]
+    ));
+}
+
+#[test]
+fn call_err() {
+    let code = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+        let mut parser = Parser::new(&mut tokenizer);
+
+        parser
+            .parse_program()
+            .err()
+            .map(|diagnostic| diagnostic.code)
+    };
+
+    assert_eq!(code("print(1 2)"), Some(DiagnosticCode::ExpectedToken));
+    assert_eq!(code("print(1,"), Some(DiagnosticCode::ExpectedExpression));
+}
@@ -1,6 +1,5 @@
 use crate::{
-    parser::{Literal, Parser, SimpleType, Variable},
-    tests::parser::declaration,
+    parser::{Expression, Literal, Parser, SimpleType, Variable},
     tokenizer::Tokenizer,
 };
 
@@ -14,10 +13,13 @@ fn declaration_ok() {
         Ok(declaration) => match (
             declaration.variable,
             declaration.union_type,
-            declaration.literal
+            declaration.value
         ) {
-            (Variable { name }, None, Literal::NumberLiteral(literal)) =>
-                name == "test" && literal == 42,
+            (
+                Variable { name, .. },
+                None,
+                Expression::Literal(Literal::NumberLiteral(literal, _)),
+            ) => name == "test" && literal == 42,
             _ => false,
         },
         Err(_) => false,
@@ -34,9 +36,13 @@ fn declaration_type_ok() {
         Ok(declaration) => match (
             declaration.variable,
             declaration.union_type,
-            declaration.literal
+            declaration.value
         ) {
-            (Variable { name }, Some(union_type), Literal::StringLiteral(literal)) =>
+            (
+                Variable { name, .. },
+                Some(union_type),
+                Expression::Literal(Literal::StringLiteral(literal, _)),
+            ) =>
                 name == "test"
                     && union_type.has_type(SimpleType::String)
                     && union_type.has_type(SimpleType::Str)
@@ -0,0 +1,81 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    parser::{Expression, Literal, Parser, UnaryOperator},
+    tokenizer::Tokenizer,
+};
+
+/** Fully parenthesized form of the expression, to check how it was grouped */
+fn grouped(expression: &Expression) -> String {
+    match expression {
+        Expression::Literal(Literal::NumberLiteral(value, _)) => value.to_string(),
+        Expression::Literal(Literal::FloatLiteral(value, _)) => format!("{:?}", value),
+        Expression::Literal(Literal::BigNumberLiteral(value, _)) => value.clone(),
+        Expression::Literal(Literal::StringLiteral(value, _)) => format!("'{}'", value),
+        Expression::Literal(Literal::BoolLiteral(value, _)) => value.to_string(),
+        Expression::Variable(variable) => variable.name.clone(),
+        Expression::Unary {
+            operator: UnaryOperator::Negate,
+            operand,
+            ..
+        } => format!("(-{})", grouped(operand)),
+        Expression::Unary {
+            operator: UnaryOperator::Not,
+            operand,
+            ..
+        } => format!("(not {})", grouped(operand)),
+        Expression::Binary {
+            operator,
+            left,
+            right,
+            ..
+        } => format!("({} {} {})", grouped(left), operator, grouped(right)),
+        Expression::Call {
+            callee, arguments, ..
+        } => {
+            let arguments: Vec<String> = arguments.iter().map(grouped).collect();
+            format!("{}({})", callee.name, arguments.join(", "))
+        }
+        Expression::FormatString { .. } => "f''".to_string(),
+    }
+}
+
+fn parse(source: &str) -> Expression {
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    parser.parse_expression().unwrap()
+}
+
+#[test]
+fn expression_precedence() {
+    assert_eq!(grouped(&parse("1 + 2 * 3")), "(1 + (2 * 3))");
+    assert_eq!(grouped(&parse("a - b - c")), "((a - b) - c)");
+    assert_eq!(grouped(&parse("a // b / c * d")), "(((a // b) / c) * d)");
+    assert_eq!(grouped(&parse("(1 + 2) * -x")), "((1 + 2) * (-x))");
+    assert_eq!(grouped(&parse("--1 - -2")), "((-(-1)) - (-2))");
+}
+
+#[test]
+fn expression_span() {
+    let expression = parse("(1 + 2) * 30");
+
+    assert_eq!(expression.span().start.offset, 1);
+    assert_eq!(expression.span().end.offset, 12);
+}
+
+#[test]
+fn expression_err() {
+    let code = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+        let mut parser = Parser::new(&mut tokenizer);
+
+        parser
+            .parse_expression()
+            .err()
+            .map(|diagnostic| diagnostic.code)
+    };
+
+    assert_eq!(code("1 +"), Some(DiagnosticCode::ExpectedExpression));
+    assert_eq!(code("(1 + 2"), Some(DiagnosticCode::ExpectedToken));
+    assert_eq!(code("* 2"), Some(DiagnosticCode::ExpectedExpression));
+}
@@ -0,0 +1,37 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    parser::{Expression, FormatStringPart, Parser},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn format_string_expressions() {
+    let mut tokenizer = Tokenizer::new("f'{name}: {scale(x) * 2}'");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_expression() {
+        Ok(Expression::FormatString { parts, .. }) => match &parts[..] {
+            [FormatStringPart::Expression(Expression::Variable(variable)), FormatStringPart::Text(text), FormatStringPart::Expression(Expression::Binary { left, .. })] =>
+                variable.name == "name"
+                    && variable.span.start.offset == 3
+                    && text == ": "
+                    && matches!(left.as_ref(), Expression::Call { span, .. } if span.start.offset == 11),
+            _ => false,
+        },
+        _ => false,
+    });
+}
+
+#[test]
+fn format_string_err() {
+    let error = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+        let mut parser = Parser::new(&mut tokenizer);
+        parser.parse_expression().err().unwrap()
+    };
+
+    let extra = error("f'{a b}'");
+    assert_eq!(extra.code, DiagnosticCode::ExpectedToken);
+    assert_eq!(extra.span.start.offset, 5);
+    assert_eq!(error("f'{}'").code, DiagnosticCode::ExpectedExpression);
+}
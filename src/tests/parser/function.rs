@@ -0,0 +1,53 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    parser::{Parser, SimpleType, Statement},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn function_def_ok() {
+    let source = "def add(a: Number, b: Number | str,) -> Number:\n    total = 0\n";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    let function = parser.parse_function_def().unwrap();
+
+    assert_eq!(function.name.name, "add");
+    assert_eq!(function.parameters.len(), 2);
+    assert!(function.parameters[1].union_type.has_type(SimpleType::Str));
+    assert!(function.return_type.unwrap().has_type(SimpleType::Number));
+    assert!(matches!(
+        &function.body.statements[..],
+        [Statement::Declaration(_)]
+    ));
+}
+
+#[test]
+fn function_def_empty() {
+    let source = "def nothing() -> ():\n    unused = 1\nafter = 2";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    let program = parser.parse_program().unwrap();
+
+    assert!(match &program.statements[..] {
+        [Statement::FunctionDef(function), Statement::Declaration(_)] =>
+            function.parameters.is_empty()
+                && function
+                    .return_type
+                    .as_ref()
+                    .unwrap()
+                    .has_type(SimpleType::Empty),
+        _ => false,
+    });
+}
+
+#[test]
+fn function_def_without_body() {
+    let mut tokenizer = Tokenizer::new("def add(a: Number) -> Number:\nafter = 2");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_function_def() {
+        Err(diagnostic) =>
+            diagnostic.code == DiagnosticCode::ExpectedIdentation && diagnostic.labels.len() == 1,
+        Ok(_) => false,
+    });
+}
@@ -10,8 +10,11 @@ fn string_literal_ok() {
 
     assert!(match parser.parse_literal() {
         Ok(value) => match value {
-            Literal::StringLiteral(value) => value == "Literal string",
-            Literal::NumberLiteral(_) => false,
+            Literal::StringLiteral(value, _) => value == "Literal string",
+            Literal::NumberLiteral(..)
+            | Literal::FloatLiteral(..)
+            | Literal::BigNumberLiteral(..)
+            | Literal::BoolLiteral(..) => false,
         },
         _ => false,
     })
@@ -24,13 +27,38 @@ fn number_literal_ok() {
 
     assert!(match parser.parse_literal() {
         Ok(value) => match value {
-            Literal::StringLiteral(_) => false,
-            Literal::NumberLiteral(value) => value == 42,
+            Literal::StringLiteral(..)
+            | Literal::FloatLiteral(..)
+            | Literal::BigNumberLiteral(..)
+            | Literal::BoolLiteral(..) => false,
+            Literal::NumberLiteral(value, _) => value == 42,
         },
         _ => false,
     })
 }
 
+#[test]
+fn float_literal_ok() {
+    let mut tokenizer = Tokenizer::new("1_000.5e-3");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_literal() {
+        Ok(Literal::FloatLiteral(value, span)) => value == 1.0005 && span.len() == 10,
+        _ => false,
+    })
+}
+
+#[test]
+fn bool_literal_ok() {
+    let mut tokenizer = Tokenizer::new("False");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_literal() {
+        Ok(Literal::BoolLiteral(value, span)) => !value && span.len() == 5,
+        _ => false,
+    })
+}
+
 #[test]
 fn literal_err() {
     let mut tokenizer = Tokenizer::new("# Comment");
@@ -1,6 +1,12 @@
+mod block;
+mod boolean;
+mod call;
 mod declaration;
-mod identation;
+mod expression;
+mod format_string;
+mod function;
 mod literal;
 mod pipe;
+mod program;
 mod union_types;
 mod variable;
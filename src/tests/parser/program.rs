@@ -0,0 +1,45 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    parser::{Parser, Statement},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn program_statements() {
+    let source = "name: str = 'Glare'\n\n# Comment\nage = 42\n";
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+    let program = parser.parse_program().unwrap();
+
+    let names: Vec<&str> = program
+        .statements
+        .iter()
+        .map(|statement| match statement {
+            Statement::Declaration(declaration) => declaration.variable.name.as_str(),
+            Statement::FunctionDef(function) => function.name.name.as_str(),
+            Statement::Expression(_) => "_",
+        })
+        .collect();
+
+    assert_eq!(names, vec!["name", "age"]);
+    assert_eq!(program.span.len(), source.len());
+}
+
+#[test]
+fn program_separator_err() {
+    let mut tokenizer = Tokenizer::new("name = 'Glare' age = 42");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_program() {
+        Err(diagnostic) => diagnostic.code == DiagnosticCode::ExpectedToken,
+        Ok(_) => false,
+    });
+}
+
+#[test]
+fn program_empty() {
+    let mut tokenizer = Tokenizer::new("\n# Nothing here\n");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(parser.parse_program().unwrap().statements.is_empty());
+}
@@ -0,0 +1,50 @@
+use super::tokens;
+use crate::token::Token;
+
+#[test]
+fn boolean_tokens() {
+    assert!(matches!(
+        tokens("not True and False")[..],
+        [
+            Token::Not,
+            Token::Bool(true),
+            Token::And,
+            Token::Bool(false),
+            Token::EOF
+        ]
+    ));
+    assert!(matches!(
+        tokens("a == b != c <= d >= e < f > g or h")[..],
+        [
+            Token::Variable(_),
+            Token::DoubleEqual,
+            Token::Variable(_),
+            Token::NotEqual,
+            Token::Variable(_),
+            Token::LessEqual,
+            Token::Variable(_),
+            Token::GreaterEqual,
+            Token::Variable(_),
+            Token::Less,
+            Token::Variable(_),
+            Token::Greater,
+            Token::Variable(_),
+            Token::Or,
+            Token::Variable(_),
+            Token::EOF,
+        ]
+    ));
+}
+
+#[test]
+fn boolean_keyword_prefix() {
+    assert!(matches!(
+        &tokens("Truely notes android order")[..],
+        [Token::Variable(a), Token::Variable(b), Token::Variable(c), Token::Variable(d), Token::EOF]
+            if [a, b, c, d] == ["Truely", "notes", "android", "order"]
+    ));
+    assert!(matches!(
+        &tokens("Bool")[..],
+        [Token::SimpleType(name), Token::EOF] if name == "Bool"
+    ));
+}
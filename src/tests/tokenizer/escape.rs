@@ -0,0 +1,62 @@
+use super::token;
+use crate::{
+    diagnostic::DiagnosticCode,
+    token::{FormatPart, SpannedToken, Token},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn escape_sequences() {
+    assert!(matches!(
+        token(r"'it\'s\n\ta \\ \u{48}\u{1F600}'"),
+        Token::String(value) if value == "it's\n\ta \\ H\u{1F600}"
+    ));
+    assert!(matches!(token(r#""say \"hi\"""#), Token::String(value) if value == "say \"hi\""));
+    assert!(match token(r"f'\`{x}\u{7B}'") {
+        Token::FormatString(parts) => matches!(
+            &parts[..],
+            [FormatPart::Text(before), FormatPart::Expression(_), FormatPart::Text(after)]
+                if before == "`" && after == "{"
+        ),
+        _ => false,
+    });
+}
+
+#[test]
+fn escape_err() {
+    let error = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+        tokenizer.get_next_token(true).err().unwrap()
+    };
+
+    let unknown = error(r"'abc\q'");
+    assert_eq!(unknown.code, DiagnosticCode::InvalidEscape);
+    assert_eq!(unknown.span.start.column, 5);
+    assert_eq!(unknown.span.len(), 2);
+
+    for source in [
+        r"'\u{}'",
+        r"'\u{D800}'",
+        r"'\u{1234567}'",
+        r"'\u{+1}'",
+        r"'\u12'",
+    ] {
+        assert_eq!(
+            error(source).code,
+            DiagnosticCode::InvalidEscape,
+            "{}",
+            source
+        );
+    }
+    assert_eq!(error(r"'\u{110000}'").span.len(), 10);
+}
+
+#[test]
+fn escaped_quote_span() {
+    let mut tokenizer = Tokenizer::new(r"'a\'b' c");
+
+    assert!(matches!(
+        tokenizer.get_next_token(true),
+        Ok(SpannedToken { token: Token::String(_), span }) if span.len() == 6
+    ));
+}
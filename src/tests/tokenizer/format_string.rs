@@ -0,0 +1,70 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    token::{FormatPart, SpannedToken, Token},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn format_string_parts() {
+    let mut tokenizer = Tokenizer::new("f'Sum {a + b}, {{literal}}'");
+
+    assert!(match tokenizer.get_next_token(true) {
+        Ok(SpannedToken {
+            token: Token::FormatString(parts),
+            span,
+        }) => match &parts[..] {
+            [FormatPart::Text(before), FormatPart::Expression(expression), FormatPart::Text(after)] =>
+                before == "Sum "
+                    && expression.start.offset == 7
+                    && expression.len() == 5
+                    && after == ", {literal}"
+                    && span.len() == 27,
+            _ => false,
+        },
+        _ => false,
+    });
+}
+
+#[test]
+fn format_string_err() {
+    let error = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+        tokenizer.get_next_token(true).err().unwrap()
+    };
+
+    let unclosed = error("f\"Total: {total\"");
+    assert_eq!(unclosed.code, DiagnosticCode::InvalidFormatString);
+    assert_eq!(unclosed.span.start.offset, 9);
+    assert_eq!(unclosed.span.len(), 6);
+
+    let unmatched = error("f`a } b`");
+    assert_eq!(unmatched.code, DiagnosticCode::InvalidFormatString);
+    assert_eq!(unmatched.span.start.column, 5);
+}
+
+#[test]
+fn format_string_nested() {
+    let expressions = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+
+        match tokenizer.get_next_token(true) {
+            Ok(SpannedToken {
+                token: Token::FormatString(parts),
+                ..
+            }) => parts
+                .iter()
+                .filter_map(|part| match part {
+                    FormatPart::Expression(span) => {
+                        Some(source[span.start.offset..span.end.offset].to_string())
+                    }
+                    FormatPart::Text(_) => None,
+                })
+                .collect::<Vec<String>>(),
+            _ => panic!("{} isn't an f-string", source),
+        }
+    };
+
+    assert_eq!(expressions("f\"a{'}'}b{'{'}\""), ["'}'", "'{'"]);
+    assert_eq!(expressions("f\"{f'{1}'}\""), ["f'{1}'"]);
+    assert_eq!(expressions("f'{(a + b) * 2}'"), ["(a + b) * 2"]);
+}
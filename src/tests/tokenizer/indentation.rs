@@ -0,0 +1,55 @@
+use super::tokens;
+use crate::{diagnostic::DiagnosticCode, driver::tokenize, token::Token};
+
+fn kinds(source: &str) -> Vec<String> {
+    tokens(source)
+        .iter()
+        .filter(|token| {
+            matches!(
+                token,
+                Token::Indent | Token::Dedent | Token::LineBreak | Token::EOF
+            )
+        })
+        .map(|token| format!("{:?}", token))
+        .collect()
+}
+
+#[test]
+fn indent_dedent() {
+    let source = "a = 1\n\tb = 2\n\t\tc = 3\n\n\t# Comment\nd = 4\n\te = 5";
+
+    assert_eq!(
+        kinds(source),
+        vec![
+            "LineBreak",
+            "Indent",
+            "LineBreak",
+            "Indent",
+            "LineBreak",
+            "Dedent",
+            "Dedent",
+            "LineBreak",
+            "Indent",
+            "Dedent",
+            "EOF",
+        ]
+    );
+}
+
+#[test]
+fn indentation_err() {
+    let code = |source: &str| tokenize(source).err().map(|diagnostic| diagnostic.code);
+
+    assert_eq!(
+        code("a = 1\n        b = 2\n    c = 3"),
+        Some(DiagnosticCode::InconsistentDedent)
+    );
+    assert_eq!(
+        code("a = 1\n \tb = 2"),
+        Some(DiagnosticCode::MixedIndentation)
+    );
+    assert_eq!(
+        code("a = 1\n    b = 2\n\tc = 3"),
+        Some(DiagnosticCode::MixedIndentation)
+    );
+}
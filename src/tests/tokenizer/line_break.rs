@@ -0,0 +1,47 @@
+use super::tokens;
+use crate::{token::Token, tokenizer::Tokenizer};
+
+#[test]
+fn line_break_collapsed() {
+    let source = "# Header\n\na = 1  # Comment\n\n    \n# Comment\nb = 2\n\n";
+
+    assert_eq!(
+        tokens(source)
+            .iter()
+            .map(|token| format!("{:?}", token))
+            .collect::<Vec<String>>(),
+        vec![
+            "Variable(\"a\")",
+            "Equal",
+            "Number(1)",
+            "LineBreak",
+            "Variable(\"b\")",
+            "Equal",
+            "Number(2)",
+            "LineBreak",
+            "EOF",
+        ]
+    );
+}
+
+#[test]
+fn eof_repeated() {
+    let mut tokenizer = Tokenizer::new("a = 1\r\n");
+
+    for _ in 0..3 {
+        tokenizer.get_next_token(true).unwrap();
+    }
+
+    assert!(matches!(
+        tokenizer.get_next_token(true).unwrap().token,
+        Token::LineBreak
+    ));
+    assert!(matches!(
+        tokenizer.get_next_token(true).unwrap().token,
+        Token::EOF
+    ));
+    assert!(matches!(
+        tokenizer.get_next_token(true).unwrap().token,
+        Token::EOF
+    ));
+}
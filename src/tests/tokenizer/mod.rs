@@ -0,0 +1,25 @@
+mod boolean;
+mod escape;
+mod format_string;
+mod indentation;
+mod line_break;
+mod multiline;
+mod number;
+mod span;
+
+use crate::{driver::tokenize, token::Token, tokenizer::Tokenizer};
+
+/** First token of the source */
+fn token(source: &str) -> Token {
+    let mut tokenizer = Tokenizer::new(source);
+    tokenizer.get_next_token(true).unwrap().token
+}
+
+/** Every token of the source, up to and including `EOF` */
+fn tokens(source: &str) -> Vec<Token> {
+    tokenize(source)
+        .unwrap()
+        .into_iter()
+        .map(|spanned| spanned.token)
+        .collect()
+}
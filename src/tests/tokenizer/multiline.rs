@@ -0,0 +1,49 @@
+use crate::{
+    diagnostic::DiagnosticCode,
+    token::{SpannedToken, Token},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn multiline_span() {
+    let mut tokenizer = Tokenizer::new("x = \"\"\"first\n  'second'\"\"\" + r'''\\n\n'''\ny");
+    tokenizer.get_next_token(true).unwrap();
+    tokenizer.get_next_token(true).unwrap();
+
+    assert!(match tokenizer.get_next_token(true) {
+        Ok(SpannedToken {
+            token: Token::String(value),
+            span,
+        }) => value == "first\n  'second'" && span.start.line == 1 && span.end.line == 2,
+        _ => false,
+    });
+    tokenizer.get_next_token(true).unwrap();
+    assert!(match tokenizer.get_next_token(true) {
+        Ok(SpannedToken {
+            token: Token::String(value),
+            span,
+        }) => value == "\\n\n" && span.end.line == 3 && span.end.column == 4,
+        _ => false,
+    });
+    assert!(matches!(
+        tokenizer.get_next_token(true),
+        Ok(SpannedToken { token: Token::LineBreak, span }) if span.start.line == 3
+    ));
+}
+
+#[test]
+fn unterminated_string() {
+    let error = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+        tokenizer.get_next_token(true).err().unwrap()
+    };
+
+    let single = error("'first\nsecond'");
+    assert_eq!(single.code, DiagnosticCode::UnterminatedString);
+    assert_eq!(single.span.len(), 6);
+    assert_eq!(single.notes.len(), 1);
+
+    let triple = error("f\"\"\"first\nsecond\"\"");
+    assert_eq!(triple.code, DiagnosticCode::UnterminatedString);
+    assert!(triple.notes.is_empty());
+}
@@ -0,0 +1,57 @@
+use super::token;
+use crate::{diagnostic::DiagnosticCode, token::Token, tokenizer::Tokenizer};
+
+#[test]
+fn number_forms() {
+    assert!(matches!(token("1_000_000"), Token::Number(1_000_000)));
+    assert!(matches!(token("0xff"), Token::Number(255)));
+    assert!(matches!(token("0X_dead_BEEF"), Token::Number(0xdead_beef)));
+    assert!(matches!(token("0o17"), Token::Number(15)));
+    assert!(matches!(token("0b1010"), Token::Number(10)));
+    assert!(matches!(
+        token("0x7fff_ffff_ffff_ffff"),
+        Token::Number(i64::MAX)
+    ));
+    assert!(matches!(token("2.75"), Token::Float(value) if value == 2.75));
+    assert!(matches!(token("1e9"), Token::Float(value) if value == 1e9));
+    assert!(matches!(token("2.5E-3"), Token::Float(value) if value == 2.5e-3));
+    assert!(matches!(token("0.1"), Token::Float(value) if value == 0.1));
+}
+
+#[test]
+fn big_numbers() {
+    assert!(matches!(
+        token("9223372036854775808"),
+        Token::BigNumber(value) if value == "9223372036854775808"
+    ));
+    assert!(matches!(
+        token("0x8000_0000_0000_0000"),
+        Token::BigNumber(value) if value == "9223372036854775808"
+    ));
+    assert!(matches!(
+        token(&format!("0b1{}", "_0000".repeat(20))),
+        Token::BigNumber(value) if value == "1208925819614629174706176"
+    ));
+    assert!(matches!(
+        token("000_123_456_789_012_345_678_901_234_567_890"),
+        Token::BigNumber(value) if value == "123456789012345678901234567890"
+    ));
+}
+
+#[test]
+fn number_err() {
+    let error = |source: &str| {
+        let mut tokenizer = Tokenizer::new(source);
+        tokenizer.get_next_token(true).err().unwrap()
+    };
+
+    for source in ["1__0", "1_", "1_.5", "0b_", "0x1_"] {
+        assert_eq!(
+            error(source).code,
+            DiagnosticCode::InvalidNumber,
+            "{}",
+            source
+        );
+    }
+    assert_eq!(error("1e309").code, DiagnosticCode::NumberTooLarge);
+}
@@ -0,0 +1,68 @@
+use crate::{
+    parser::Parser,
+    span::Position,
+    token::{SpannedToken, Token},
+    tokenizer::Tokenizer,
+};
+
+#[test]
+fn token_span_ok() {
+    let mut tokenizer = Tokenizer::new("# Comment\ntest = 42");
+
+    assert!(match tokenizer.get_next_token(true) {
+        Ok(SpannedToken {
+            token: Token::Variable(name),
+            span,
+        }) =>
+            name == "test"
+                && span.start
+                    == Position {
+                        offset: 10,
+                        line: 2,
+                        column: 1,
+                    }
+                && span.end
+                    == Position {
+                        offset: 14,
+                        line: 2,
+                        column: 5,
+                    },
+        _ => false,
+    })
+}
+
+#[test]
+fn token_span_peek() {
+    // Leading whitespace would open a block, so skip some after a token
+    let mut tokenizer = Tokenizer::new("=  42");
+    tokenizer.get_next_token(true).unwrap();
+
+    assert!(match (
+        tokenizer.get_next_token(false),
+        tokenizer.get_next_token(true),
+    ) {
+        (Ok(peeked), Ok(consumed)) =>
+            peeked.span == consumed.span && consumed.span.start.column == 4,
+        _ => false,
+    });
+    assert!(tokenizer.position().offset == 5);
+}
+
+#[test]
+fn declaration_span_ok() {
+    let mut tokenizer = Tokenizer::new("test: String | str = 'Testing'");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Ok(declaration) =>
+            declaration.span.start.offset == 0
+                && declaration.span.end.offset == 30
+                && declaration.variable.span.len() == 4
+                && declaration
+                    .union_type
+                    .map(|union_type| union_type.span.start.column == 7
+                        && union_type.span.end.column == 19)
+                    .unwrap_or(false),
+        Err(_) => false,
+    })
+}
@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::translation::{detect_language, Language, Message, TranslationKey};
+
+fn detect(flag: Option<&str>, vars: &[(&str, &str)]) -> Result<Language, Message> {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    detect_language(flag, |name| vars.get(name).cloned())
+}
+
+#[test]
+fn detect_posix_locale() {
+    assert_eq!(
+        detect(None, &[("LANG", "pt_BR.UTF-8")]),
+        Ok(Language::Portuguese)
+    );
+    assert_eq!(detect(None, &[("LANG", "ja_JP")]), Ok(Language::Japanese));
+    assert_eq!(detect(None, &[("LANG", "C.UTF-8")]), Ok(Language::English));
+    assert_eq!(
+        detect(
+            None,
+            &[("LANG", "pt_BR.UTF-8"), ("LC_MESSAGES", "ja_JP.UTF-8")]
+        ),
+        Ok(Language::Japanese)
+    );
+    assert_eq!(
+        detect(None, &[("LC_MESSAGES", "ja_JP"), ("LC_ALL", "de_DE@euro")]),
+        Ok(Language::English)
+    );
+}
+
+#[test]
+fn detect_precedence() {
+    assert_eq!(
+        detect(
+            Some("ja"),
+            &[("GLARE_LANGUAGE", "Portuguese"), ("LANG", "en_US")]
+        ),
+        Ok(Language::Japanese)
+    );
+    assert_eq!(
+        detect(None, &[("GLARE_LANGUAGE", "Português"), ("LANG", "ja_JP")]),
+        Ok(Language::Portuguese)
+    );
+    assert_eq!(
+        detect(None, &[("GLARE_LANGUAGE", ""), ("LANG", "ja_JP")]),
+        Ok(Language::Japanese)
+    );
+}
+
+#[test]
+fn detect_unknown_language() {
+    assert_eq!(
+        detect(Some("Klingon"), &[("LANG", "pt_BR")]),
+        Err(Message::new(
+            TranslationKey::UnknownLanguage,
+            json!({ "language": "Klingon" })
+        ))
+    );
+    assert_eq!(
+        detect(None, &[("GLARE_LANGUAGE", "es_ES")]),
+        Err(Message::new(
+            TranslationKey::UnknownLanguage,
+            json!({ "language": "es_ES" })
+        ))
+    );
+}
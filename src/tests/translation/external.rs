@@ -0,0 +1,101 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    parser::Parser,
+    tokenizer::Tokenizer,
+    translation::{detect_language, untranslated_keys, Language, TranslationKey, Translator},
+};
+
+/** Creates a locale directory with the given files, unique to each test */
+fn locale_directory(test: &str, files: &[(&str, &str)]) -> PathBuf {
+    let directory = env::temp_dir().join(format!("glare-locales-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&directory).unwrap();
+
+    for (name, contents) in files {
+        fs::write(directory.join(name), contents).unwrap();
+    }
+
+    directory
+}
+
+fn detect_in(directory: &Path, requested: &str) -> Result<Language, TranslationKey> {
+    let directory = directory.display().to_string();
+
+    detect_language(Some(requested), |name| match name {
+        "GLARE_LOCALE_DIR" => Some(directory.clone()),
+        _ => None,
+    })
+    .map_err(|message| message.key)
+}
+
+#[test]
+fn external_locale_ok() {
+    let directory = locale_directory(
+        "ok",
+        &[(
+            "es.json",
+            r#"{ "ExpectedToken": "Se esperaba {expected} (se encontró {found})", "TokenEOF": "fin del archivo" }"#,
+        )],
+    );
+
+    let language = detect_in(&directory, "es_ES.UTF-8").unwrap();
+    let translator = Translator::new(language);
+    let mut tokenizer = Tokenizer::new("test: Number");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match (language, parser.parse_declaration()) {
+        (Language::External(locale), Err(diagnostic)) =>
+            locale.name == "es"
+                && translator.translate(&diagnostic.message)
+                    == "Se esperaba = (se encontró fin del archivo)"
+                && translator.translate(&diagnostic.labels[0].message)
+                    == "type of 'test' declared here"
+                && untranslated_keys(locale).len() == TranslationKey::ALL.len() - 2,
+        _ => false,
+    });
+}
+
+#[test]
+fn external_locale_invalid() {
+    let directory = locale_directory(
+        "invalid",
+        &[
+            (
+                "de.json",
+                r#"{ "ExpectedPipe": "Pipe erwartet", "Typo": "?" }"#,
+            ),
+            ("fr.json", r#"{ "ExpectedPipe": "Pipe attendu {found" }"#),
+            ("it.json", r#"[ "ExpectedPipe" ]"#),
+            ("es.json", r#"{ "ExpectedPipe": 1 }"#),
+            ("sv.json", r#"{ "ExpectedPipe": "#),
+        ],
+    );
+
+    assert_eq!(
+        detect_in(&directory, "de"),
+        Err(TranslationKey::UnknownLocaleKeys)
+    );
+    assert_eq!(
+        detect_in(&directory, "fr_FR"),
+        Err(TranslationKey::InvalidLocaleTemplate)
+    );
+    assert_eq!(
+        detect_in(&directory, "it"),
+        Err(TranslationKey::LocaleNotAnObject)
+    );
+    assert_eq!(
+        detect_in(&directory, "es"),
+        Err(TranslationKey::LocaleTemplateNotString)
+    );
+    assert_eq!(
+        detect_in(&directory, "sv"),
+        Err(TranslationKey::InvalidLocaleFile)
+    );
+    assert_eq!(
+        detect_in(&directory, "nl"),
+        Err(TranslationKey::UnknownLanguage)
+    );
+}
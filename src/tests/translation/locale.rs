@@ -0,0 +1,42 @@
+use std::collections::BTreeSet;
+
+use phf::{phf_map, Map};
+
+use crate::translation::{
+    get_locale, lookup_template, untranslated_keys, Language, TranslationKey,
+};
+
+static PARTIAL: Map<&'static str, &'static str> = phf_map! {
+    "ExpectedPipe" => "Pipe esperado",
+    "Obsolete" => "Obsoleto",
+};
+
+#[test]
+fn locales_same_keys() {
+    let keys: BTreeSet<&str> = TranslationKey::ALL.iter().map(|key| key.into()).collect();
+
+    for language in Language::ALL {
+        let locale: BTreeSet<&str> = get_locale(*language).keys().into_iter().collect();
+
+        assert_eq!(locale, keys, "{:?} locale is out of date", language);
+    }
+}
+
+#[test]
+fn locale_fallback() {
+    assert_eq!(lookup_template(&PARTIAL, "ExpectedPipe"), "Pipe esperado");
+    assert_eq!(
+        lookup_template(&PARTIAL, "ExpectedVariable"),
+        "Expected variable (got {found})"
+    );
+    assert_eq!(lookup_template(&PARTIAL, "Unknown"), "Unknown");
+}
+
+#[test]
+fn locale_untranslated_keys() {
+    let untranslated = untranslated_keys(&PARTIAL);
+
+    assert!(untranslated.len() == TranslationKey::ALL.len() - 1);
+    assert!(!untranslated.contains(&"ExpectedPipe"));
+    assert!(untranslated.contains(&"ExpectedVariable"));
+}
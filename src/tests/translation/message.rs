@@ -0,0 +1,77 @@
+use serde_json::json;
+
+use crate::{
+    parser::Parser,
+    tokenizer::Tokenizer,
+    translation::{Language, Message, TranslationKey},
+};
+
+#[test]
+fn message_portuguese() {
+    let mut tokenizer = Tokenizer::new("test: Number 42");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) =>
+            diagnostic.message.key == TranslationKey::ExpectedToken
+                && diagnostic.message.english() == "Expected = (got number '42')"
+                && diagnostic.message.in_language(Language::Portuguese)
+                    == "= era esperado (encontrado: número '42')"
+                && diagnostic.labels[0]
+                    .message
+                    .in_language(Language::Portuguese)
+                    == "tipo de 'test' declarado aqui",
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn message_tokenizer_portuguese() {
+    let mut tokenizer = Tokenizer::new("1e999999999999999999");
+
+    assert!(match tokenizer.get_next_token(true) {
+        Err(diagnostic) =>
+            diagnostic.message.in_language(Language::Portuguese)
+                == "1e999999999999999999 é grande demais para o tipo 'Number'",
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn message_japanese() {
+    let mut tokenizer = Tokenizer::new("test = :");
+    let mut parser = Parser::new(&mut tokenizer);
+
+    assert!(match parser.parse_declaration() {
+        Err(diagnostic) =>
+            diagnostic.message.in_language(Language::Japanese)
+                == "式が必要です (: が見つかりました)"
+                && diagnostic
+                    .severity
+                    .message()
+                    .in_language(Language::Japanese)
+                    == "エラー",
+        Ok(_) => false,
+    })
+}
+
+#[test]
+fn templates_render() {
+    for language in Language::ALL {
+        // "None" translates to its own name in English
+        for key in &TranslationKey::ALL[..TranslationKey::ALL.len() - 1] {
+            let message = Message::new(
+                *key,
+                json!({
+                    "symbol": "?", "index": 0, "value": "x", "min": 0, "max": 1,
+                    "found": "x", "name": "x", "expected": "x", "first": "x",
+                    "second": "x", "token": "x", "language": "x", "keys": "x", "path": "x",
+                    "error": "x", "key": "x", "command": "x", "option": "x", "target": "x",
+                    "program": "x"
+                }),
+            );
+
+            assert!(message.in_language(*language) != <&'static str>::from(key));
+        }
+    }
+}
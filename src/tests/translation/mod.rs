@@ -0,0 +1,5 @@
+mod detection;
+mod external;
+mod locale;
+mod message;
+mod translator;
@@ -0,0 +1,45 @@
+use std::thread;
+
+use crate::{
+    diagnostic::render::Renderer,
+    parser::Parser,
+    tokenizer::Tokenizer,
+    translation::{Language, TranslationKey, Translator},
+};
+
+fn render_in(source: &str, language: Language) -> String {
+    let translator = Translator::new(language);
+    let mut tokenizer = Tokenizer::new(source);
+    let mut parser = Parser::new(&mut tokenizer);
+
+    match parser.parse_declaration() {
+        Err(diagnostic) => {
+            Renderer::new(source, "main.glare", false, &translator).render(&diagnostic)
+        }
+        Ok(_) => String::new(),
+    }
+}
+
+#[test]
+fn translator_render_portuguese() {
+    assert!(render_in("test: Number", Language::Portuguese)
+        .starts_with("erro[E0009]: = era esperado (encontrado: fim do arquivo)\n"));
+}
+
+#[test]
+fn translator_concurrent_languages() {
+    let handles: Vec<_> = Language::ALL
+        .iter()
+        .map(|language| {
+            let language = *language;
+            thread::spawn(move || (language, render_in("test 42", language)))
+        })
+        .collect();
+
+    for handle in handles {
+        let (language, rendered) = handle.join().unwrap();
+        let expected = Translator::new(language).get_template(&TranslationKey::SeverityError);
+
+        assert!(rendered.starts_with(expected));
+    }
+}
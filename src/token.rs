@@ -1,32 +1,166 @@
 use std::fmt::Display;
 
-#[derive(Debug)]
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    span::Span,
+    translation::{Message, TranslationKey},
+};
+
+/** Piece of an f-string, as read by the tokenizer */
+#[derive(Debug, Serialize)]
+pub enum FormatPart {
+    Text(String),
+    /** Source of an interpolated expression, without its braces */
+    Expression(Span),
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Serialize)]
 pub enum Token {
     String(String),
+    FormatString(Vec<FormatPart>),
     Number(i64),
+    Float(f64),
+    /** Integer beyond the range of `i64`, in base 10 */
+    BigNumber(String),
     SimpleType(String),
     Pipe,
-    Tab,
+    Indent,
+    Dedent,
     LineBreak,
     EOF,
     Variable(String),
     Colon,
     Equal,
+    Def,
+    LeftParen,
+    RightParen,
+    Comma,
+    Arrow,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    DoubleSlash,
+    Percent,
+    Bool(bool),
+    And,
+    Or,
+    Not,
+    DoubleEqual,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Token {
+    /** Translatable description of the token, used in diagnostics */
+    pub fn describe(&self) -> Message {
+        match &self {
+            Token::String(value) => {
+                Message::new(TranslationKey::TokenString, json!({ "value": value }))
+            }
+            Token::FormatString(_) => Message::new(TranslationKey::TokenFormatString, json!({})),
+            Token::Number(value) => {
+                Message::new(TranslationKey::TokenNumber, json!({ "value": value }))
+            }
+            Token::Float(value) => {
+                Message::new(TranslationKey::TokenNumber, json!({ "value": value }))
+            }
+            Token::BigNumber(value) => {
+                Message::new(TranslationKey::TokenNumber, json!({ "value": value }))
+            }
+            Token::SimpleType(value) => {
+                Message::new(TranslationKey::TokenType, json!({ "value": value }))
+            }
+            Token::Pipe => Message::new(TranslationKey::TokenPipe, json!({})),
+            Token::Indent => Message::new(TranslationKey::TokenIndent, json!({})),
+            Token::Dedent => Message::new(TranslationKey::TokenDedent, json!({})),
+            Token::LineBreak => Message::new(TranslationKey::TokenLineBreak, json!({})),
+            Token::EOF => Message::new(TranslationKey::TokenEOF, json!({})),
+            Token::Variable(value) => {
+                Message::new(TranslationKey::TokenVariable, json!({ "value": value }))
+            }
+            Token::Colon => Message::new(TranslationKey::TokenColon, json!({})),
+            Token::Equal => Message::new(TranslationKey::TokenEqual, json!({})),
+            Token::Def => Message::new(TranslationKey::TokenDef, json!({})),
+            Token::LeftParen => Message::new(TranslationKey::TokenLeftParen, json!({})),
+            Token::RightParen => Message::new(TranslationKey::TokenRightParen, json!({})),
+            Token::Comma => Message::new(TranslationKey::TokenComma, json!({})),
+            Token::Arrow => Message::new(TranslationKey::TokenArrow, json!({})),
+            Token::Plus => Message::new(TranslationKey::TokenPlus, json!({})),
+            Token::Minus => Message::new(TranslationKey::TokenMinus, json!({})),
+            Token::Star => Message::new(TranslationKey::TokenStar, json!({})),
+            Token::Slash => Message::new(TranslationKey::TokenSlash, json!({})),
+            Token::DoubleSlash => Message::new(TranslationKey::TokenDoubleSlash, json!({})),
+            Token::Percent => Message::new(TranslationKey::TokenPercent, json!({})),
+            Token::Bool(value) => Message::new(
+                TranslationKey::TokenBool,
+                json!({ "value": if *value { "True" } else { "False" } }),
+            ),
+            Token::And => Message::new(TranslationKey::TokenAnd, json!({})),
+            Token::Or => Message::new(TranslationKey::TokenOr, json!({})),
+            Token::Not => Message::new(TranslationKey::TokenNot, json!({})),
+            Token::DoubleEqual => Message::new(TranslationKey::TokenDoubleEqual, json!({})),
+            Token::NotEqual => Message::new(TranslationKey::TokenNotEqual, json!({})),
+            Token::Less => Message::new(TranslationKey::TokenLess, json!({})),
+            Token::LessEqual => Message::new(TranslationKey::TokenLessEqual, json!({})),
+            Token::Greater => Message::new(TranslationKey::TokenGreater, json!({})),
+            Token::GreaterEqual => Message::new(TranslationKey::TokenGreaterEqual, json!({})),
+        }
+    }
 }
 
 impl Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
             Token::String(value) => write!(f, "string '{}'", value),
+            Token::FormatString(_) => write!(f, "f-string"),
             Token::Number(value) => write!(f, "number '{}'", value),
+            Token::Float(value) => write!(f, "number '{:?}'", value),
+            Token::BigNumber(value) => write!(f, "number '{}'", value),
             Token::SimpleType(value) => write!(f, "type '{}'", value),
             Token::Pipe => write!(f, "pipe"),
-            Token::Tab => write!(f, "tab"),
+            Token::Indent => write!(f, "indent"),
+            Token::Dedent => write!(f, "end of block"),
             Token::LineBreak => write!(f, "new line"),
             Token::EOF => write!(f, "EOF"),
             Token::Variable(value) => write!(f, "variable '{}'", value),
             Token::Colon => write!(f, ":"),
             Token::Equal => write!(f, "="),
+            Token::Def => write!(f, "def"),
+            Token::LeftParen => write!(f, "("),
+            Token::RightParen => write!(f, ")"),
+            Token::Comma => write!(f, ","),
+            Token::Arrow => write!(f, "->"),
+            Token::Plus => write!(f, "+"),
+            Token::Minus => write!(f, "-"),
+            Token::Star => write!(f, "*"),
+            Token::Slash => write!(f, "/"),
+            Token::DoubleSlash => write!(f, "//"),
+            Token::Percent => write!(f, "%"),
+            Token::Bool(true) => write!(f, "True"),
+            Token::Bool(false) => write!(f, "False"),
+            Token::And => write!(f, "and"),
+            Token::Or => write!(f, "or"),
+            Token::Not => write!(f, "not"),
+            Token::DoubleEqual => write!(f, "=="),
+            Token::NotEqual => write!(f, "!="),
+            Token::Less => write!(f, "<"),
+            Token::LessEqual => write!(f, "<="),
+            Token::Greater => write!(f, ">"),
+            Token::GreaterEqual => write!(f, ">="),
         }
     }
 }
+
+/** Token along with the region of the source it was read from */
+#[derive(Debug, Serialize)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: Span,
+}
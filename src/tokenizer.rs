@@ -1,17 +1,49 @@
+use std::{mem, sync::OnceLock};
+
 use regex::Regex;
+use serde_json::json;
 
-use crate::token::Token;
+use crate::{
+    diagnostic::{Diagnostic, DiagnosticCode},
+    span::{Position, Span},
+    token::{FormatPart, SpannedToken, Token},
+    translation::{Message, TranslationKey},
+};
 
 pub enum TokenizerSpecType {
     Number,
     String,
+    RawString,
+    FormatString,
+    UnterminatedString,
     SimpleType,
     Pipe,
     Skip,
-    Tab,
+    LineBreak,
     Variable,
     Colon,
     Equal,
+    Def,
+    LeftParen,
+    RightParen,
+    Comma,
+    Arrow,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    DoubleSlash,
+    Percent,
+    Bool,
+    And,
+    Or,
+    Not,
+    DoubleEqual,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
 }
 
 pub struct TokenizerSpec {
@@ -20,55 +52,209 @@ pub struct TokenizerSpec {
 }
 
 pub struct Tokenizer<'a> {
-    cursor: usize,
+    position: Position,
+    /** Whether a token was consumed since the last line break */
+    line_has_tokens: bool,
+    /** Whether the indentation of the current line still has to be read */
+    at_line_start: bool,
+    /** Widths of the enclosing blocks, starting with the top level */
+    indents: Vec<usize>,
+    /** Character used by the first indented line */
+    indent_style: Option<char>,
     string: &'a str,
-    specs: Vec<TokenizerSpec>,
+    specs: &'static [TokenizerSpec],
 }
 
-impl<'a> Tokenizer<'a> {
-    /** Initializes tokenizer */
-    pub fn new(string: &'a str) -> Self {
-        let specs = vec![
+static SPECS: OnceLock<Vec<TokenizerSpec>> = OnceLock::new();
+
+/** Token rules, tried in order, compiled the first time a tokenizer needs them */
+fn specs() -> &'static [TokenizerSpec] {
+    SPECS.get_or_init(|| {
+        vec![
             // Numbers
             TokenizerSpec {
-                regex: Regex::new(r"^\d+").unwrap(),
+                regex: Regex::new(r"^0(?:[xX][0-9a-fA-F_]+|[oO][0-7_]+|[bB][01_]+)").unwrap(),
                 kind: TokenizerSpecType::Number,
             },
-            // String delimiters
             TokenizerSpec {
-                regex: Regex::new(r"^'[^']*'").unwrap(),
+                regex: Regex::new(r"^\d[\d_]*(?:\.\d[\d_]*)?(?:[eE][+-]?\d[\d_]*)?").unwrap(),
+                kind: TokenizerSpecType::Number,
+            },
+            // String delimiters, with triple quotes first
+            TokenizerSpec {
+                regex: Regex::new(r"(?s)^f'''(?:[^\\]|\\.)*?'''").unwrap(),
+                kind: TokenizerSpecType::FormatString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r#"(?s)^f"""(?:[^\\]|\\.)*?""""#).unwrap(),
+                kind: TokenizerSpecType::FormatString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"(?s)^r'''(?:[^\\]|\\.)*?'''").unwrap(),
+                kind: TokenizerSpecType::RawString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r#"(?s)^r"""(?:[^\\]|\\.)*?""""#).unwrap(),
+                kind: TokenizerSpecType::RawString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"(?s)^'''(?:[^\\]|\\.)*?'''").unwrap(),
+                kind: TokenizerSpecType::String,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r#"(?s)^"""(?:[^\\]|\\.)*?""""#).unwrap(),
+                kind: TokenizerSpecType::String,
+            },
+            // Opening quotes without matching closing ones
+            TokenizerSpec {
+                regex: Regex::new(r#"^[fr]?(?:'''|""")"#).unwrap(),
+                kind: TokenizerSpecType::UnterminatedString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^f'(?:[^'\\\n]|\\.)*'").unwrap(),
+                kind: TokenizerSpecType::FormatString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r#"^f"(?:[^"\\\n]|\\.)*""#).unwrap(),
+                kind: TokenizerSpecType::FormatString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^f`(?:[^`\\]|\\(?s:.))*`").unwrap(),
+                kind: TokenizerSpecType::FormatString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^r'(?:[^'\\\n]|\\.)*'").unwrap(),
+                kind: TokenizerSpecType::RawString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r#"^r"(?:[^"\\\n]|\\.)*""#).unwrap(),
+                kind: TokenizerSpecType::RawString,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^'(?:[^'\\\n]|\\.)*'").unwrap(),
                 kind: TokenizerSpecType::String,
             },
             TokenizerSpec {
-                regex: Regex::new(r#"^"[^"]*""#).unwrap(),
+                regex: Regex::new(r#"^"(?:[^"\\\n]|\\.)*""#).unwrap(),
                 kind: TokenizerSpecType::String,
             },
             TokenizerSpec {
-                regex: Regex::new(r"^`[^`]*`").unwrap(),
+                regex: Regex::new(r"^`(?:[^`\\]|\\(?s:.))*`").unwrap(),
                 kind: TokenizerSpecType::String,
             },
+            TokenizerSpec {
+                regex: Regex::new(r#"^[fr]?['"`]"#).unwrap(),
+                kind: TokenizerSpecType::UnterminatedString,
+            },
             // Separators
             TokenizerSpec {
-                regex: Regex::new(r"^(?:\t|(?:[ ]{4})){1}").unwrap(),
-                kind: TokenizerSpecType::Tab,
+                regex: Regex::new(r"^\r?\n").unwrap(),
+                kind: TokenizerSpecType::LineBreak,
             },
             TokenizerSpec {
                 regex: Regex::new(r"^\|").unwrap(),
                 kind: TokenizerSpecType::Pipe,
             },
             TokenizerSpec {
-                regex: Regex::new(r"^\s+").unwrap(),
+                regex: Regex::new(r"^[^\S\n]+").unwrap(),
                 kind: TokenizerSpecType::Skip,
             },
             TokenizerSpec {
                 regex: Regex::new(r"^\#.*").unwrap(),
                 kind: TokenizerSpecType::Skip,
             },
+            // Keywords
+            TokenizerSpec {
+                regex: Regex::new(r"^def\b").unwrap(),
+                kind: TokenizerSpecType::Def,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^(?:True|False)\b").unwrap(),
+                kind: TokenizerSpecType::Bool,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^and\b").unwrap(),
+                kind: TokenizerSpecType::And,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^or\b").unwrap(),
+                kind: TokenizerSpecType::Or,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^not\b").unwrap(),
+                kind: TokenizerSpecType::Not,
+            },
             // Types
             TokenizerSpec {
-                regex: Regex::new(r"^(?:Number|str|String|None|\(\)|Never|List|Array)").unwrap(),
+                regex: Regex::new(r"^(?:Number|str|String|Bool|None|Never|List|Array)\b").unwrap(),
                 kind: TokenizerSpecType::SimpleType,
             },
+            // Punctuation
+            TokenizerSpec {
+                regex: Regex::new(r"^\(").unwrap(),
+                kind: TokenizerSpecType::LeftParen,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^\)").unwrap(),
+                kind: TokenizerSpecType::RightParen,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^,").unwrap(),
+                kind: TokenizerSpecType::Comma,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^->").unwrap(),
+                kind: TokenizerSpecType::Arrow,
+            },
+            // Operators
+            TokenizerSpec {
+                regex: Regex::new(r"^\+").unwrap(),
+                kind: TokenizerSpecType::Plus,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^-").unwrap(),
+                kind: TokenizerSpecType::Minus,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^\*").unwrap(),
+                kind: TokenizerSpecType::Star,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^//").unwrap(),
+                kind: TokenizerSpecType::DoubleSlash,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^/").unwrap(),
+                kind: TokenizerSpecType::Slash,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^%").unwrap(),
+                kind: TokenizerSpecType::Percent,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^==").unwrap(),
+                kind: TokenizerSpecType::DoubleEqual,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^!=").unwrap(),
+                kind: TokenizerSpecType::NotEqual,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^<=").unwrap(),
+                kind: TokenizerSpecType::LessEqual,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^>=").unwrap(),
+                kind: TokenizerSpecType::GreaterEqual,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^<").unwrap(),
+                kind: TokenizerSpecType::Less,
+            },
+            TokenizerSpec {
+                regex: Regex::new(r"^>").unwrap(),
+                kind: TokenizerSpecType::Greater,
+            },
             TokenizerSpec {
                 regex: Regex::new(r#"^:"#).unwrap(),
                 kind: TokenizerSpecType::Colon,
@@ -79,82 +265,617 @@ impl<'a> Tokenizer<'a> {
             },
             // Variable
             TokenizerSpec {
-                regex: Regex::new(r"^[^0-9]{1}\w*").unwrap(),
+                regex: Regex::new(r"^[^\W\d]\w*").unwrap(),
                 kind: TokenizerSpecType::Variable,
             },
-        ];
+        ]
+    })
+}
 
+impl<'a> Tokenizer<'a> {
+    /** Initializes tokenizer */
+    pub fn new(string: &'a str) -> Self {
         /* Initializes new tokenizer with the
         cursor set to the initial position */
         Tokenizer {
             string,
-            cursor: 0,
-            specs,
+            position: Position::default(),
+            line_has_tokens: false,
+            at_line_start: true,
+            indents: vec![0],
+            indent_style: None,
+            specs: specs(),
         }
     }
 
-    pub fn get_next_token(&mut self, consume: bool) -> Result<Option<Token>, String> {
+    /**
+     * Returns the next token, moving past it when `consume` is set.
+     * Line breaks are only emitted after lines with tokens, so blank and
+     * comment-only lines are collapsed. Changes of indentation at the
+     * start of a line produce `Token::Indent` and `Token::Dedent`, and the
+     * end of the input closes every open block before being reported as
+     * `Token::EOF` as many times as it is asked for.
+     */
+    pub fn get_next_token(&mut self, consume: bool) -> Result<SpannedToken, Diagnostic> {
+        if self.at_line_start {
+            if let Some(result) = self.indentation(consume) {
+                return result;
+            }
+        }
+
         if self.has_more_tokens() {
-            for spec in &self.specs {
-                if let Some(correspondence) = spec.regex.captures(&self.string[self.cursor..]) {
+            for spec in self.specs {
+                if let Some(correspondence) =
+                    spec.regex.captures(&self.string[self.position.offset..])
+                {
                     match correspondence.iter().next() {
                         Some(Some(value)) => {
                             let value = value.as_str();
-                            let length = value.len();
+                            let end = self.position.advance(value);
+                            let span = Span::new(self.position, end);
 
                             let result = match spec.kind {
-                                TokenizerSpecType::Number => match value.parse() {
-                                    Ok(parsed) => Ok(Some(Token::Number(parsed))),
-                                    Err(_) => {
-                                        Err(format!("{} is too large for type 'Number'", value))
-                                    }
-                                },
+                                TokenizerSpecType::Number => number(value, span),
                                 TokenizerSpecType::String => {
-                                    Ok(Some(Token::String((&value[1..length - 1]).to_owned())))
+                                    let (opening, contents) = string_contents(value);
+                                    unescape(contents, self.position.advance(&value[..opening]))
+                                        .map(Token::String)
+                                }
+                                TokenizerSpecType::RawString => {
+                                    Ok(Token::String(string_contents(value).1.to_owned()))
+                                }
+                                TokenizerSpecType::FormatString => {
+                                    let (opening, contents) = string_contents(value);
+                                    self.format_parts(
+                                        contents,
+                                        self.position.advance(&value[..opening]),
+                                    )
+                                    .map(Token::FormatString)
+                                }
+                                TokenizerSpecType::UnterminatedString => {
+                                    let line = self.string[self.position.offset..]
+                                        .lines()
+                                        .next()
+                                        .unwrap_or(value);
+                                    let diagnostic = Diagnostic::error(
+                                        DiagnosticCode::UnterminatedString,
+                                        Message::new(TranslationKey::UnterminatedString, json!({})),
+                                        Span::new(self.position, self.position.advance(line)),
+                                    );
+
+                                    match value.ends_with("'''") || value.ends_with("\"\"\"") {
+                                        true => Err(diagnostic),
+                                        // A single quote, which can't span several lines
+                                        false => Err(diagnostic.with_note(Message::new(
+                                            TranslationKey::MultilineStrings,
+                                            json!({}),
+                                        ))),
+                                    }
                                 }
                                 TokenizerSpecType::SimpleType => {
-                                    Ok(Some(Token::SimpleType(value.to_owned())))
+                                    Ok(Token::SimpleType(value.to_owned()))
                                 }
-                                TokenizerSpecType::Pipe => Ok(Some(Token::Pipe)),
+                                TokenizerSpecType::Pipe => Ok(Token::Pipe),
                                 TokenizerSpecType::Skip => {
                                     // Skip this token
-                                    self.cursor += length;
+                                    self.position = end;
                                     return self.get_next_token(consume);
                                 }
-                                TokenizerSpecType::Tab => Ok(Some(Token::Tab)),
+                                TokenizerSpecType::LineBreak => {
+                                    if !self.line_has_tokens {
+                                        // Nothing to end on this line
+                                        self.position = end;
+                                        self.at_line_start = true;
+                                        return self.get_next_token(consume);
+                                    }
+
+                                    Ok(Token::LineBreak)
+                                }
                                 TokenizerSpecType::Variable => {
-                                    Ok(Some(Token::Variable(value.to_owned())))
+                                    Ok(Token::Variable(value.to_owned()))
                                 }
-                                TokenizerSpecType::Colon => Ok(Some(Token::Colon)),
-                                TokenizerSpecType::Equal => Ok(Some(Token::Equal)),
+                                TokenizerSpecType::Colon => Ok(Token::Colon),
+                                TokenizerSpecType::Equal => Ok(Token::Equal),
+                                TokenizerSpecType::Def => Ok(Token::Def),
+                                TokenizerSpecType::LeftParen => Ok(Token::LeftParen),
+                                TokenizerSpecType::RightParen => Ok(Token::RightParen),
+                                TokenizerSpecType::Comma => Ok(Token::Comma),
+                                TokenizerSpecType::Arrow => Ok(Token::Arrow),
+                                TokenizerSpecType::Plus => Ok(Token::Plus),
+                                TokenizerSpecType::Minus => Ok(Token::Minus),
+                                TokenizerSpecType::Star => Ok(Token::Star),
+                                TokenizerSpecType::Slash => Ok(Token::Slash),
+                                TokenizerSpecType::DoubleSlash => Ok(Token::DoubleSlash),
+                                TokenizerSpecType::Percent => Ok(Token::Percent),
+                                TokenizerSpecType::Bool => Ok(Token::Bool(value == "True")),
+                                TokenizerSpecType::And => Ok(Token::And),
+                                TokenizerSpecType::Or => Ok(Token::Or),
+                                TokenizerSpecType::Not => Ok(Token::Not),
+                                TokenizerSpecType::DoubleEqual => Ok(Token::DoubleEqual),
+                                TokenizerSpecType::NotEqual => Ok(Token::NotEqual),
+                                TokenizerSpecType::Less => Ok(Token::Less),
+                                TokenizerSpecType::LessEqual => Ok(Token::LessEqual),
+                                TokenizerSpecType::Greater => Ok(Token::Greater),
+                                TokenizerSpecType::GreaterEqual => Ok(Token::GreaterEqual),
                             };
 
                             if consume {
-                                self.cursor += length;
+                                self.position = end;
+                                match result {
+                                    Ok(Token::LineBreak) => {
+                                        self.line_has_tokens = false;
+                                        self.at_line_start = true;
+                                    }
+                                    _ => self.line_has_tokens = true,
+                                }
                             }
 
-                            return result;
+                            return result.map(|token| SpannedToken { token, span });
                         }
                         _ => continue,
                     }
                 }
             }
 
-            if let Some(current_char) = self.string.chars().nth(self.cursor) {
-                return Err(format!(
-                    "Unexpected symbol {} (at index {})",
-                    current_char, self.cursor
+            if let Some(current_char) = self.string[self.position.offset..].chars().next() {
+                let end = self
+                    .position
+                    .advance(&self.string[self.position.offset..][..current_char.len_utf8()]);
+
+                return Err(Diagnostic::error(
+                    DiagnosticCode::UnexpectedSymbol,
+                    Message::new(
+                        TranslationKey::UnexpectedSymbolComplete,
+                        json!({ "symbol": current_char, "index": self.position.offset }),
+                    ),
+                    Span::new(self.position, end),
                 ));
             }
 
-            return Err(format!("Unexpected symbol at index {}", self.cursor));
+            return Err(Diagnostic::error(
+                DiagnosticCode::UnexpectedSymbol,
+                Message::new(
+                    TranslationKey::UnexpectedSymbol,
+                    json!({ "index": self.position.offset }),
+                ),
+                Span::new(self.position, self.position),
+            ));
+        }
+
+        /* No more tokens, close the blocks still open */
+        let span = Span::new(self.position, self.position);
+
+        if self.indents.len() > 1 {
+            if consume {
+                self.indents.pop();
+            }
+
+            return Ok(SpannedToken {
+                token: Token::Dedent,
+                span,
+            });
+        }
+
+        Ok(SpannedToken {
+            token: Token::EOF,
+            span,
+        })
+    }
+
+    /**
+     * Compares the indentation of the current line with the enclosing blocks.
+     * Returns `None` once the line continues the current block.
+     */
+    fn indentation(&mut self, consume: bool) -> Option<Result<SpannedToken, Diagnostic>> {
+        let rest = &self.string[self.position.offset..];
+        let content = rest.trim_start_matches([' ', '\t']);
+        let whitespace = &rest[..rest.len() - content.len()];
+
+        // Blank and comment-only lines don't open or close blocks
+        if content.is_empty() || content.starts_with(['\r', '\n', '#']) {
+            return None;
+        }
+
+        let start = self.position.advance(whitespace);
+        let span = Span::new(self.position, start);
+        let width = whitespace.len();
+        let current = self.indents.last().copied().unwrap_or(0);
+
+        let mixed = whitespace.contains(' ') && whitespace.contains('\t');
+        if let Some(style) = self.indent_style {
+            if mixed || whitespace.chars().any(|c| c != style) {
+                return Some(Err(Diagnostic::error(
+                    DiagnosticCode::MixedIndentation,
+                    Message::new(TranslationKey::MixedIndentation, json!({})),
+                    span,
+                )
+                .with_note(Message::new(
+                    match style {
+                        '\t' => TranslationKey::IndentedWithTabs,
+                        _ => TranslationKey::IndentedWithSpaces,
+                    },
+                    json!({}),
+                ))));
+            }
+        } else if mixed {
+            return Some(Err(Diagnostic::error(
+                DiagnosticCode::MixedIndentation,
+                Message::new(TranslationKey::MixedIndentation, json!({})),
+                span,
+            )));
+        }
+
+        if width > current {
+            if consume {
+                self.indents.push(width);
+                self.indent_style = whitespace.chars().next();
+                self.position = start;
+                self.at_line_start = false;
+            }
+
+            return Some(Ok(SpannedToken {
+                token: Token::Indent,
+                span,
+            }));
+        }
+
+        if width < current {
+            if !self.indents.contains(&width) {
+                return Some(Err(Diagnostic::error(
+                    DiagnosticCode::InconsistentDedent,
+                    Message::new(TranslationKey::InconsistentDedent, json!({})),
+                    span,
+                )));
+            }
+
+            // The line is read again for every block it closes
+            if consume {
+                self.indents.pop();
+            }
+
+            return Some(Ok(SpannedToken {
+                token: Token::Dedent,
+                span: Span::new(start, start),
+            }));
+        }
+
+        self.position = start;
+        self.at_line_start = false;
+        None
+    }
+
+    /**
+     * Splits the contents of an f-string, starting at `start`, into text and
+     * interpolated expressions. Doubled braces stand for a literal brace and
+     * escape sequences are decoded in the text.
+     */
+    fn format_parts(&self, contents: &str, start: Position) -> Result<Vec<FormatPart>, Diagnostic> {
+        let mut parts = vec![];
+        let mut text = String::new();
+        let mut index = 0;
+
+        while let Some(character) = contents[index..].chars().next() {
+            let rest = &contents[index..];
+
+            if rest.starts_with("{{") || rest.starts_with("}}") {
+                text.push(character);
+                index += 2;
+                continue;
+            }
+
+            let brace = Span::new(
+                start.advance(&contents[..index]),
+                start.advance(&contents[..index + 1]),
+            );
+
+            match character {
+                '\\' => {
+                    let (decoded, length) = escape(rest, brace.start)?;
+                    text.push(decoded);
+                    index += length;
+                }
+                '{' => match interpolation_end(rest) {
+                    Some(close) => {
+                        if !text.is_empty() {
+                            parts.push(FormatPart::Text(mem::take(&mut text)));
+                        }
+
+                        parts.push(FormatPart::Expression(Span::new(
+                            brace.end,
+                            start.advance(&contents[..index + close]),
+                        )));
+                        index += close + 1;
+                    }
+                    None => {
+                        return Err(Diagnostic::error(
+                            DiagnosticCode::InvalidFormatString,
+                            Message::new(TranslationKey::UnclosedInterpolation, json!({})),
+                            Span::new(brace.start, start.advance(contents)),
+                        )
+                        .with_note(Message::new(TranslationKey::DoubleBraces, json!({}))))
+                    }
+                },
+                '}' => {
+                    return Err(Diagnostic::error(
+                        DiagnosticCode::InvalidFormatString,
+                        Message::new(TranslationKey::UnmatchedBrace, json!({})),
+                        brace,
+                    )
+                    .with_note(Message::new(TranslationKey::DoubleBraces, json!({}))))
+                }
+                _ => {
+                    text.push(character);
+                    index += character.len_utf8();
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            parts.push(FormatPart::Text(text));
         }
 
-        /* No more tokens */
-        Ok(None)
+        Ok(parts)
+    }
+
+    /** Tokenizer over an expression interpolated in an f-string */
+    pub fn interpolation(&self, span: Span) -> Tokenizer<'a> {
+        let mut tokenizer = Tokenizer::new(&self.string[..span.end.offset]);
+        tokenizer.position = span.start;
+        tokenizer.at_line_start = false;
+        tokenizer
+    }
+
+    /** Current position of the cursor in the source */
+    pub fn position(&self) -> Position {
+        self.position
     }
 
     fn has_more_tokens(&mut self) -> bool {
-        self.cursor < self.string.len()
+        self.position.offset < self.string.len()
+    }
+}
+
+/**
+ * Reads a decimal, hexadecimal, octal or binary integer, or a decimal float.
+ * Underscores may only separate digits, or follow the prefix. Integers that
+ * don't fit in an `i64` are kept in base 10, as they can be of any size.
+ */
+fn number(value: &str, span: Span) -> Result<Token, Diagnostic> {
+    let (radix, digits) = match value.get(..2) {
+        Some("0x" | "0X") => (16, &value[2..]),
+        Some("0o" | "0O") => (8, &value[2..]),
+        Some("0b" | "0B") => (2, &value[2..]),
+        _ => (10, value),
+    };
+
+    let characters: Vec<char> = digits.chars().collect();
+    let misplaced = characters.iter().enumerate().any(|(index, &character)| {
+        let before = match index {
+            0 => radix != 10,
+            _ => characters[index - 1].is_digit(radix),
+        };
+        let after = matches!(characters.get(index + 1), Some(next) if next.is_digit(radix));
+
+        character == '_' && !(before && after)
+    });
+
+    if misplaced {
+        return Err(Diagnostic::error(
+            DiagnosticCode::InvalidNumber,
+            Message::new(
+                TranslationKey::MisplacedUnderscore,
+                json!({ "value": value }),
+            ),
+            span,
+        )
+        .with_note(Message::new(
+            TranslationKey::UnderscoreBetweenDigits,
+            json!({}),
+        )));
+    }
+
+    let digits = digits.replace('_', "");
+
+    if radix == 10 && digits.contains(['.', 'e', 'E']) {
+        return match digits.parse::<f64>() {
+            Ok(parsed) if parsed.is_finite() => Ok(Token::Float(parsed)),
+            _ => Err(Diagnostic::error(
+                DiagnosticCode::NumberTooLarge,
+                Message::new(TranslationKey::NumberTooLarge, json!({ "value": value })),
+                span,
+            )
+            .with_note(Message::new(
+                TranslationKey::FloatRange,
+                json!({ "max": format!("{:e}", f64::MAX) }),
+            ))),
+        };
+    }
+
+    match i64::from_str_radix(&digits, radix) {
+        Ok(parsed) => Ok(Token::Number(parsed)),
+        Err(_) => Ok(Token::BigNumber(to_decimal(&digits, radix))),
     }
 }
+
+/** Converts the digits of an integer of any size to base 10 */
+fn to_decimal(digits: &str, radix: u32) -> String {
+    const BASE: u64 = 1_000_000_000;
+
+    // Groups of nine decimal digits, least significant first
+    let mut groups: Vec<u64> = vec![0];
+
+    for digit in digits.chars().filter_map(|digit| digit.to_digit(radix)) {
+        let mut carry = digit as u64;
+
+        for group in groups.iter_mut() {
+            let value = *group * radix as u64 + carry;
+            *group = value % BASE;
+            carry = value / BASE;
+        }
+
+        if carry > 0 {
+            groups.push(carry);
+        }
+    }
+
+    let mut decimal = groups.pop().unwrap_or(0).to_string();
+    for group in groups.iter().rev() {
+        decimal += &format!("{:09}", group);
+    }
+
+    decimal
+}
+
+/** Splits a string token into the length of its opening delimiter and its contents */
+fn string_contents(value: &str) -> (usize, &str) {
+    let prefix = value.len() - value.trim_start_matches(['f', 'r']).len();
+    let quotes = match value[prefix..].starts_with("'''") || value[prefix..].starts_with("\"\"\"") {
+        true => 3,
+        false => 1,
+    };
+
+    (
+        prefix + quotes,
+        &value[prefix + quotes..value.len() - quotes],
+    )
+}
+
+/** Decodes the escape sequences in the contents of a string starting at `start` */
+fn unescape(contents: &str, start: Position) -> Result<String, Diagnostic> {
+    let mut decoded = String::new();
+    let mut index = 0;
+
+    while let Some(character) = contents[index..].chars().next() {
+        match character {
+            '\\' => {
+                let (character, length) =
+                    escape(&contents[index..], start.advance(&contents[..index]))?;
+                decoded.push(character);
+                index += length;
+            }
+            _ => {
+                decoded.push(character);
+                index += character.len_utf8();
+            }
+        }
+    }
+
+    Ok(decoded)
+}
+
+/**
+ * Finds the brace closing the interpolation opened at the start of `rest`.
+ * Brackets opened by the expression and string literals, such as nested
+ * f-strings, are skipped, so the braces they contain don't close it.
+ */
+fn interpolation_end(rest: &str) -> Option<usize> {
+    let mut closers = vec![];
+    let mut index = 0;
+
+    while let Some(character) = rest[index..].chars().next() {
+        match character {
+            '\'' | '"' | '`' => {
+                index += string_length(&rest[index..])?;
+                continue;
+            }
+            '(' => closers.push(')'),
+            '[' => closers.push(']'),
+            '{' => closers.push('}'),
+            ')' | ']' | '}' if closers.last() == Some(&character) => {
+                closers.pop();
+
+                if closers.is_empty() {
+                    return Some(index);
+                }
+            }
+            _ => (),
+        }
+
+        index += character.len_utf8();
+    }
+
+    None
+}
+
+/** Length of the string literal at the start of `rest`, `None` when it isn't closed */
+fn string_length(rest: &str) -> Option<usize> {
+    let quote = match rest.chars().next() {
+        Some(quote @ ('\'' | '"')) if rest.starts_with(&quote.to_string().repeat(3)) => &rest[..3],
+        _ => &rest[..1],
+    };
+    let mut index = quote.len();
+
+    while let Some(character) = rest[index..].chars().next() {
+        if rest[index..].starts_with(quote) {
+            return Some(index + quote.len());
+        }
+
+        index += character.len_utf8();
+
+        // The escaped character can't end the string
+        if character == '\\' {
+            index += rest[index..].chars().next()?.len_utf8();
+        }
+    }
+
+    None
+}
+
+/**
+ * Decodes the escape sequence at the start of `rest`, whose backslash is at
+ * `start`. Returns the character along with the length of the sequence.
+ */
+fn escape(rest: &str, start: Position) -> Result<(char, usize), Diagnostic> {
+    let character = rest[1..].chars().next().unwrap_or('\\');
+    let length = 1 + character.len_utf8();
+
+    let decoded = match character {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        '0' => '\0',
+        '\\' | '\'' | '"' | '`' => character,
+        'u' => {
+            let sequence = match rest.find('}') {
+                Some(close) if rest[2..].starts_with('{') => &rest[..close + 1],
+                _ => &rest[..length],
+            };
+            let digits = sequence.trim_start_matches("\\u{").trim_end_matches('}');
+
+            return match u32::from_str_radix(digits, 16)
+                .ok()
+                .and_then(char::from_u32)
+            {
+                Some(decoded)
+                    if sequence.ends_with('}')
+                        && digits.len() <= 6
+                        && digits.chars().all(|digit| digit.is_ascii_hexdigit()) =>
+                {
+                    Ok((decoded, sequence.len()))
+                }
+                _ => Err(Diagnostic::error(
+                    DiagnosticCode::InvalidEscape,
+                    Message::new(
+                        TranslationKey::InvalidUnicodeEscape,
+                        json!({ "value": sequence }),
+                    ),
+                    Span::new(start, start.advance(sequence)),
+                )
+                .with_note(Message::new(TranslationKey::UnicodeEscapeFormat, json!({})))),
+            };
+        }
+        _ => {
+            return Err(Diagnostic::error(
+                DiagnosticCode::InvalidEscape,
+                Message::new(
+                    TranslationKey::UnknownEscape,
+                    json!({ "value": &rest[..length] }),
+                ),
+                Span::new(start, start.advance(&rest[..length])),
+            )
+            .with_note(Message::new(TranslationKey::SupportedEscapes, json!({}))))
+        }
+    };
+
+    Ok((decoded, length))
+}
@@ -1,45 +1,410 @@
-use std::env;
+use std::{env, path::Path};
 
-use serde_json::{from_str, Value};
-use tinytemplate::TinyTemplate;
+use phf::Map;
+use serde::{Serialize, Serializer};
+use serde_json::{json, Value};
+use tinytemplate::{format_unescaped, TinyTemplate};
 
 use crate::languages::english::ENGLISH;
+use crate::languages::external::{load_locale, ExternalLocale};
+use crate::languages::japanese::JAPANESE;
 use crate::languages::portuguese::PORTUGUESE;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TranslationKey {
     UnexpectedSymbol,
     UnexpectedSymbolComplete,
+    NumberTooLarge,
     ExpectedLiteral,
     ExpectedType,
+    InvalidType,
+    ExpectedPipe,
+    ExpectedIdentation,
+    ExpectedVariable,
+    ExpectedToken,
+    ExpectedEither,
+    TypeDeclaredHere,
+    VariableDeclaredHere,
+    DeclarationWithoutValue,
+    AddBeforeValue,
+    TokenString,
+    TokenNumber,
+    TokenType,
+    TokenPipe,
+    TokenIndent,
+    TokenLineBreak,
+    TokenEOF,
+    TokenVariable,
+    TokenColon,
+    TokenEqual,
+    SeverityError,
+    SeverityWarning,
+    SeverityNote,
+    UntranslatedKeys,
+    UnknownLanguage,
+    InvalidLocaleFile,
+    UnknownLocaleKeys,
+    InvalidLocaleTemplate,
+    LocaleNotAnObject,
+    LocaleTemplateNotString,
+    MismatchedType,
+    Usage,
+    UnknownCommand,
+    MissingInput,
+    MissingValue,
+    UnknownTarget,
+    UnknownOption,
+    ReadFailed,
+    WriteFailed,
+    RunFailed,
+    InvalidValue,
+    TokenDedent,
+    InconsistentDedent,
+    MixedIndentation,
+    IndentedWithTabs,
+    IndentedWithSpaces,
+    TokenDef,
+    TokenLeftParen,
+    TokenRightParen,
+    TokenComma,
+    TokenArrow,
+    FunctionDeclaredHere,
+    TokenPlus,
+    TokenMinus,
+    TokenStar,
+    TokenSlash,
+    TokenDoubleSlash,
+    ExpectedExpression,
+    TokenPercent,
+    WrongArgumentCount,
+    TokenFormatString,
+    TokenRightBrace,
+    UnclosedInterpolation,
+    UnmatchedBrace,
+    DoubleBraces,
+    UnknownEscape,
+    SupportedEscapes,
+    InvalidUnicodeEscape,
+    UnicodeEscapeFormat,
+    UnterminatedString,
+    MultilineStrings,
+    MisplacedUnderscore,
+    UnderscoreBetweenDigits,
+    FloatRange,
+    TokenBool,
+    TokenAnd,
+    TokenOr,
+    TokenNot,
+    TokenDoubleEqual,
+    TokenNotEqual,
+    TokenLess,
+    TokenLessEqual,
+    TokenGreater,
+    TokenGreaterEqual,
+    ChainedComparison,
+    CombineWithAnd,
+    PreviousComparison,
+    CalledBeforeDefinition,
     None,
 }
 
+impl TranslationKey {
+    pub const ALL: &'static [TranslationKey] = &[
+        TranslationKey::UnexpectedSymbol,
+        TranslationKey::UnexpectedSymbolComplete,
+        TranslationKey::NumberTooLarge,
+        TranslationKey::ExpectedLiteral,
+        TranslationKey::ExpectedType,
+        TranslationKey::InvalidType,
+        TranslationKey::ExpectedPipe,
+        TranslationKey::ExpectedIdentation,
+        TranslationKey::ExpectedVariable,
+        TranslationKey::ExpectedToken,
+        TranslationKey::ExpectedEither,
+        TranslationKey::TypeDeclaredHere,
+        TranslationKey::VariableDeclaredHere,
+        TranslationKey::DeclarationWithoutValue,
+        TranslationKey::AddBeforeValue,
+        TranslationKey::TokenString,
+        TranslationKey::TokenNumber,
+        TranslationKey::TokenType,
+        TranslationKey::TokenPipe,
+        TranslationKey::TokenIndent,
+        TranslationKey::TokenLineBreak,
+        TranslationKey::TokenEOF,
+        TranslationKey::TokenVariable,
+        TranslationKey::TokenColon,
+        TranslationKey::TokenEqual,
+        TranslationKey::SeverityError,
+        TranslationKey::SeverityWarning,
+        TranslationKey::SeverityNote,
+        TranslationKey::UntranslatedKeys,
+        TranslationKey::UnknownLanguage,
+        TranslationKey::InvalidLocaleFile,
+        TranslationKey::UnknownLocaleKeys,
+        TranslationKey::InvalidLocaleTemplate,
+        TranslationKey::LocaleNotAnObject,
+        TranslationKey::LocaleTemplateNotString,
+        TranslationKey::MismatchedType,
+        TranslationKey::Usage,
+        TranslationKey::UnknownCommand,
+        TranslationKey::MissingInput,
+        TranslationKey::MissingValue,
+        TranslationKey::UnknownTarget,
+        TranslationKey::UnknownOption,
+        TranslationKey::ReadFailed,
+        TranslationKey::WriteFailed,
+        TranslationKey::RunFailed,
+        TranslationKey::InvalidValue,
+        TranslationKey::TokenDedent,
+        TranslationKey::InconsistentDedent,
+        TranslationKey::MixedIndentation,
+        TranslationKey::IndentedWithTabs,
+        TranslationKey::IndentedWithSpaces,
+        TranslationKey::TokenDef,
+        TranslationKey::TokenLeftParen,
+        TranslationKey::TokenRightParen,
+        TranslationKey::TokenComma,
+        TranslationKey::TokenArrow,
+        TranslationKey::FunctionDeclaredHere,
+        TranslationKey::TokenPlus,
+        TranslationKey::TokenMinus,
+        TranslationKey::TokenStar,
+        TranslationKey::TokenSlash,
+        TranslationKey::TokenDoubleSlash,
+        TranslationKey::ExpectedExpression,
+        TranslationKey::TokenPercent,
+        TranslationKey::WrongArgumentCount,
+        TranslationKey::TokenFormatString,
+        TranslationKey::TokenRightBrace,
+        TranslationKey::UnclosedInterpolation,
+        TranslationKey::UnmatchedBrace,
+        TranslationKey::DoubleBraces,
+        TranslationKey::UnknownEscape,
+        TranslationKey::SupportedEscapes,
+        TranslationKey::InvalidUnicodeEscape,
+        TranslationKey::UnicodeEscapeFormat,
+        TranslationKey::UnterminatedString,
+        TranslationKey::MultilineStrings,
+        TranslationKey::MisplacedUnderscore,
+        TranslationKey::UnderscoreBetweenDigits,
+        TranslationKey::FloatRange,
+        TranslationKey::TokenBool,
+        TranslationKey::TokenAnd,
+        TranslationKey::TokenOr,
+        TranslationKey::TokenNot,
+        TranslationKey::TokenDoubleEqual,
+        TranslationKey::TokenNotEqual,
+        TranslationKey::TokenLess,
+        TranslationKey::TokenLessEqual,
+        TranslationKey::TokenGreater,
+        TranslationKey::TokenGreaterEqual,
+        TranslationKey::ChainedComparison,
+        TranslationKey::CombineWithAnd,
+        TranslationKey::PreviousComparison,
+        TranslationKey::CalledBeforeDefinition,
+        TranslationKey::None,
+    ];
+}
+
 impl From<&TranslationKey> for &'static str {
     fn from(val: &TranslationKey) -> Self {
         match val {
             TranslationKey::UnexpectedSymbol => "UnexpectedSymbol",
             TranslationKey::UnexpectedSymbolComplete => "UnexpectedSymbolComplete",
+            TranslationKey::NumberTooLarge => "NumberTooLarge",
             TranslationKey::ExpectedLiteral => "ExpectedLiteral",
             TranslationKey::ExpectedType => "ExpectedType",
+            TranslationKey::InvalidType => "InvalidType",
+            TranslationKey::ExpectedPipe => "ExpectedPipe",
+            TranslationKey::ExpectedIdentation => "ExpectedIdentation",
+            TranslationKey::ExpectedVariable => "ExpectedVariable",
+            TranslationKey::ExpectedToken => "ExpectedToken",
+            TranslationKey::ExpectedEither => "ExpectedEither",
+            TranslationKey::TypeDeclaredHere => "TypeDeclaredHere",
+            TranslationKey::VariableDeclaredHere => "VariableDeclaredHere",
+            TranslationKey::DeclarationWithoutValue => "DeclarationWithoutValue",
+            TranslationKey::AddBeforeValue => "AddBeforeValue",
+            TranslationKey::TokenString => "TokenString",
+            TranslationKey::TokenNumber => "TokenNumber",
+            TranslationKey::TokenType => "TokenType",
+            TranslationKey::TokenPipe => "TokenPipe",
+            TranslationKey::TokenIndent => "TokenIndent",
+            TranslationKey::TokenLineBreak => "TokenLineBreak",
+            TranslationKey::TokenEOF => "TokenEOF",
+            TranslationKey::TokenVariable => "TokenVariable",
+            TranslationKey::TokenColon => "TokenColon",
+            TranslationKey::TokenEqual => "TokenEqual",
+            TranslationKey::SeverityError => "SeverityError",
+            TranslationKey::SeverityWarning => "SeverityWarning",
+            TranslationKey::SeverityNote => "SeverityNote",
+            TranslationKey::UntranslatedKeys => "UntranslatedKeys",
+            TranslationKey::UnknownLanguage => "UnknownLanguage",
+            TranslationKey::InvalidLocaleFile => "InvalidLocaleFile",
+            TranslationKey::UnknownLocaleKeys => "UnknownLocaleKeys",
+            TranslationKey::InvalidLocaleTemplate => "InvalidLocaleTemplate",
+            TranslationKey::LocaleNotAnObject => "LocaleNotAnObject",
+            TranslationKey::LocaleTemplateNotString => "LocaleTemplateNotString",
+            TranslationKey::MismatchedType => "MismatchedType",
+            TranslationKey::Usage => "Usage",
+            TranslationKey::UnknownCommand => "UnknownCommand",
+            TranslationKey::MissingInput => "MissingInput",
+            TranslationKey::MissingValue => "MissingValue",
+            TranslationKey::UnknownTarget => "UnknownTarget",
+            TranslationKey::UnknownOption => "UnknownOption",
+            TranslationKey::ReadFailed => "ReadFailed",
+            TranslationKey::WriteFailed => "WriteFailed",
+            TranslationKey::RunFailed => "RunFailed",
+            TranslationKey::InvalidValue => "InvalidValue",
+            TranslationKey::TokenDedent => "TokenDedent",
+            TranslationKey::InconsistentDedent => "InconsistentDedent",
+            TranslationKey::MixedIndentation => "MixedIndentation",
+            TranslationKey::IndentedWithTabs => "IndentedWithTabs",
+            TranslationKey::IndentedWithSpaces => "IndentedWithSpaces",
+            TranslationKey::TokenDef => "TokenDef",
+            TranslationKey::TokenLeftParen => "TokenLeftParen",
+            TranslationKey::TokenRightParen => "TokenRightParen",
+            TranslationKey::TokenComma => "TokenComma",
+            TranslationKey::TokenArrow => "TokenArrow",
+            TranslationKey::FunctionDeclaredHere => "FunctionDeclaredHere",
+            TranslationKey::TokenPlus => "TokenPlus",
+            TranslationKey::TokenMinus => "TokenMinus",
+            TranslationKey::TokenStar => "TokenStar",
+            TranslationKey::TokenSlash => "TokenSlash",
+            TranslationKey::TokenDoubleSlash => "TokenDoubleSlash",
+            TranslationKey::ExpectedExpression => "ExpectedExpression",
+            TranslationKey::TokenPercent => "TokenPercent",
+            TranslationKey::WrongArgumentCount => "WrongArgumentCount",
+            TranslationKey::TokenFormatString => "TokenFormatString",
+            TranslationKey::TokenRightBrace => "TokenRightBrace",
+            TranslationKey::UnclosedInterpolation => "UnclosedInterpolation",
+            TranslationKey::UnmatchedBrace => "UnmatchedBrace",
+            TranslationKey::DoubleBraces => "DoubleBraces",
+            TranslationKey::UnknownEscape => "UnknownEscape",
+            TranslationKey::SupportedEscapes => "SupportedEscapes",
+            TranslationKey::InvalidUnicodeEscape => "InvalidUnicodeEscape",
+            TranslationKey::UnicodeEscapeFormat => "UnicodeEscapeFormat",
+            TranslationKey::UnterminatedString => "UnterminatedString",
+            TranslationKey::MultilineStrings => "MultilineStrings",
+            TranslationKey::MisplacedUnderscore => "MisplacedUnderscore",
+            TranslationKey::UnderscoreBetweenDigits => "UnderscoreBetweenDigits",
+            TranslationKey::FloatRange => "FloatRange",
+            TranslationKey::TokenBool => "TokenBool",
+            TranslationKey::TokenAnd => "TokenAnd",
+            TranslationKey::TokenOr => "TokenOr",
+            TranslationKey::TokenNot => "TokenNot",
+            TranslationKey::TokenDoubleEqual => "TokenDoubleEqual",
+            TranslationKey::TokenNotEqual => "TokenNotEqual",
+            TranslationKey::TokenLess => "TokenLess",
+            TranslationKey::TokenLessEqual => "TokenLessEqual",
+            TranslationKey::TokenGreater => "TokenGreater",
+            TranslationKey::TokenGreaterEqual => "TokenGreaterEqual",
+            TranslationKey::ChainedComparison => "ChainedComparison",
+            TranslationKey::CombineWithAnd => "CombineWithAnd",
+            TranslationKey::PreviousComparison => "PreviousComparison",
+            TranslationKey::CalledBeforeDefinition => "CalledBeforeDefinition",
             TranslationKey::None => "None",
         }
     }
 }
 
+impl Serialize for TranslationKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.into())
+    }
+}
+
+/**
+ * User-facing text, kept as a translation key and its parameters
+ * so it can be rendered in any language. Parameters may contain
+ * other messages, which are translated along with it.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Message {
+    pub key: TranslationKey,
+    pub params: Value,
+}
+
+impl Message {
+    pub fn new(key: TranslationKey, params: Value) -> Self {
+        Message { key, params }
+    }
+
+    /** Renders the message in the given language */
+    pub fn in_language(&self, language: Language) -> String {
+        match language {
+            Language::English => self.english(),
+            _ => Translator::new(language).translate(self),
+        }
+    }
+
+    pub fn english(&self) -> String {
+        ENGLISH_TRANSLATOR.with(|translator| translator.translate(self))
+    }
+}
+
+/** Set of templates, indexed by translation key name */
+pub trait Locale: Sync {
+    fn template(&self, key: &str) -> Option<&'static str>;
+    #[cfg(test)]
+    fn keys(&self) -> Vec<&str>;
+}
+
+impl Locale for Map<&'static str, &'static str> {
+    fn template(&self, key: &str) -> Option<&'static str> {
+        self.get(key).copied()
+    }
+
+    #[cfg(test)]
+    fn keys(&self) -> Vec<&str> {
+        self.keys().copied().collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Language {
     English,
     Portuguese,
     Japanese,
+    External(&'static ExternalLocale),
+}
+
+impl Language {
+    /** Languages built into the compiler */
+    pub const ALL: &'static [Language] =
+        &[Language::English, Language::Portuguese, Language::Japanese];
+}
+
+impl TryFrom<&str> for Language {
+    type Error = ();
+
+    /** Accepts language names as well as POSIX locales (`language[_territory][.codeset][@modifier]`) */
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        match string {
+            "Portuguese" | "Português" => return Ok(Language::Portuguese),
+            "Japanese" | "日本語" => return Ok(Language::Japanese),
+            "English" => return Ok(Language::English),
+            _ => (),
+        }
+
+        let code = string
+            .split(['.', '@'])
+            .next()
+            .and_then(|locale| locale.split(['_', '-']).next())
+            .unwrap_or("");
+
+        match code.to_lowercase().as_str() {
+            "pt" => Ok(Language::Portuguese),
+            "ja" => Ok(Language::Japanese),
+            "en" | "c" | "posix" => Ok(Language::English),
+            _ => Err(()),
+        }
+    }
 }
 
 impl From<String> for Language {
     fn from(string: String) -> Self {
-        match string.as_str() {
-            "Portuguese" | "Português" => Language::Portuguese,
-            "Japanese" | "日本語" => Language::Japanese,
-            "English" => Language::English,
-            _ => Language::English,
-        }
+        Language::try_from(string.as_str()).unwrap_or(Language::English)
     }
 }
 
@@ -49,62 +414,209 @@ impl From<Language> for String {
             Language::English => "English".to_string(),
             Language::Portuguese => "Portuguese".to_string(),
             Language::Japanese => "Japanese".to_string(),
+            Language::External(locale) => locale.name.clone(),
         }
     }
 }
 
-static mut LANGUAGE: Language = Language::English;
-static mut TEMPLATE_ENGINE: Option<TinyTemplate> = None;
+thread_local! {
+    /** Built once, since JSON output and fallbacks render every message in English */
+    static ENGLISH_TRANSLATOR: Translator = Translator::new(Language::English);
+}
 
-pub fn configure_language() {
-    let env_language = env::var("GLARE_LANGUAGE").unwrap_or_else(|_| Language::English.into());
+/** Renders messages in a single language */
+pub struct Translator {
+    language: Language,
+    template_engine: TinyTemplate<'static>,
+}
 
-    unsafe {
-        LANGUAGE = Language::from(env_language);
-        TEMPLATE_ENGINE = Some(TinyTemplate::new());
+impl Translator {
+    pub fn new(language: Language) -> Self {
+        let mut template_engine = TinyTemplate::new();
+        template_engine.set_default_formatter(&format_unescaped);
 
-        if let Some(template_engine) = &mut TEMPLATE_ENGINE {
-            // templates
-            let translation_keys = vec![
-                TranslationKey::UnexpectedSymbol,
-                TranslationKey::UnexpectedSymbolComplete,
-                TranslationKey::ExpectedLiteral,
-                TranslationKey::ExpectedType,
-                TranslationKey::None,
-            ];
+        for translation_key in TranslationKey::ALL {
+            let key: &'static str = translation_key.into();
 
-            for translation_key in &translation_keys {
+            // A malformed translation is replaced by the English template
+            if template_engine
+                .add_template(key, get_language_template(language, translation_key))
+                .is_err()
+            {
                 template_engine
-                    .add_template(translation_key.into(), get_template(translation_key))
+                    .add_template(key, lookup_template(&ENGLISH, key))
                     .unwrap();
             }
         }
+
+        Translator {
+            language,
+            template_engine,
+        }
     }
-}
 
-pub fn get_template(key: &TranslationKey) -> &'static str {
-    let key: &'static str = key.into();
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn get_template(&self, key: &TranslationKey) -> &'static str {
+        get_language_template(self.language, key)
+    }
+
+    pub fn get_translated(&self, key: &TranslationKey, params: &Value) -> Result<String, ()> {
+        self.render(key.into(), params)
+    }
 
-    unsafe {
-        match LANGUAGE {
-            Language::English => ENGLISH[key],
-            Language::Portuguese => PORTUGUESE[key],
-            Language::Japanese => todo!(),
+    /** Renders the message, falling back to English and then to the key name */
+    pub fn translate(&self, message: &Message) -> String {
+        let key: &'static str = (&message.key).into();
+
+        match self.get_translated(&message.key, &message.params) {
+            Ok(result) => result,
+            Err(_) if self.language != Language::English => message.english(),
+            Err(_) => key.to_string(),
+        }
+    }
+
+    fn render(&self, key: &str, params: &Value) -> Result<String, ()> {
+        match self.template_engine.render(key, &self.resolve(params)?) {
+            Ok(result) => Ok(result),
+            Err(_) => Err(()),
+        }
+    }
+
+    /** Replaces the messages nested in the parameters by their translation */
+    fn resolve(&self, params: &Value) -> Result<Value, ()> {
+        match params {
+            Value::Object(map) => match (map.get("key"), map.get("params")) {
+                (Some(Value::String(key)), Some(params)) if map.len() == 2 => {
+                    Ok(Value::String(self.render(key, params)?))
+                }
+                _ => {
+                    let mut resolved = serde_json::Map::new();
+
+                    for (name, value) in map {
+                        resolved.insert(name.clone(), self.resolve(value)?);
+                    }
+
+                    Ok(Value::Object(resolved))
+                }
+            },
+            _ => Ok(params.clone()),
         }
     }
 }
 
-pub fn get_translated(key: &TranslationKey, params: &str) -> Result<String, ()> {
-    let value: Value = from_str(params).unwrap();
+/**
+ * Selects the language from, in order: the `--lang` flag, `GLARE_LANGUAGE`
+ * and the POSIX locale (`LC_ALL`, `LC_MESSAGES`, `LANG`). Languages that are
+ * not built in are looked up in the `GLARE_LOCALE_DIR` directory. An explicitly
+ * requested language that is unknown is returned as an error, while an
+ * unsupported system locale silently falls back to English.
+ */
+pub fn detect_language(
+    flag: Option<&str>,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<Language, Message> {
+    let locale_directory = var("GLARE_LOCALE_DIR").filter(|directory| !directory.is_empty());
+    let find = |name: &str| -> Result<Option<Language>, Message> {
+        if let Ok(language) = Language::try_from(name) {
+            return Ok(Some(language));
+        }
 
-    unsafe {
-        if let Some(template_engine) = &mut TEMPLATE_ENGINE {
-            return match template_engine.render(key.into(), &value) {
-                Ok(result) => Ok(result),
-                Err(_) => Err(()),
-            };
+        match &locale_directory {
+            Some(directory) => Ok(load_locale(Path::new(directory), name)?.map(Language::External)),
+            None => Ok(None),
         }
+    };
+
+    let requested = flag
+        .map(|flag| flag.to_string())
+        .or_else(|| var("GLARE_LANGUAGE"))
+        .filter(|requested| !requested.is_empty());
+
+    if let Some(requested) = requested {
+        return match find(&requested)? {
+            Some(language) => Ok(language),
+            None => Err(Message::new(
+                TranslationKey::UnknownLanguage,
+                json!({ "language": requested }),
+            )),
+        };
+    }
+
+    match ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| var(name))
+        .find(|locale| !locale.is_empty())
+    {
+        Some(locale) => Ok(find(&locale)?.unwrap_or(Language::English)),
+        None => Ok(Language::English),
     }
+}
+
+/** Creates the translator for the language selected by the flag or the environment */
+pub fn configure_language(flag: Option<&str>) -> Translator {
+    let translator = match detect_language(flag, |name| env::var(name).ok()) {
+        Ok(language) => Translator::new(language),
+        Err(message) => {
+            let translator = Translator::new(Language::English);
+            warn(&translator, &message);
+            translator
+        }
+    };
+
+    let untranslated = untranslated_keys(get_locale(translator.language()));
+
+    if !untranslated.is_empty() {
+        let warning = Message::new(
+            TranslationKey::UntranslatedKeys,
+            json!({
+                "language": String::from(translator.language()),
+                "keys": untranslated.join(", ")
+            }),
+        );
+
+        warn(&translator, &warning);
+    }
+
+    translator
+}
+
+fn warn(translator: &Translator, message: &Message) {
+    eprintln!(
+        "{}: {}",
+        translator.translate(&Message::new(TranslationKey::SeverityWarning, json!({}))),
+        translator.translate(message)
+    );
+}
+
+pub fn get_language_template(language: Language, key: &TranslationKey) -> &'static str {
+    lookup_template(get_locale(language), key.into())
+}
+
+pub fn get_locale(language: Language) -> &'static dyn Locale {
+    match language {
+        Language::English => &ENGLISH,
+        Language::Portuguese => &PORTUGUESE,
+        Language::Japanese => &JAPANESE,
+        Language::External(locale) => locale,
+    }
+}
+
+/** Looks up a template in the locale, then in English, then uses the key name itself */
+pub fn lookup_template(locale: &dyn Locale, key: &'static str) -> &'static str {
+    locale
+        .template(key)
+        .or_else(|| ENGLISH.template(key))
+        .unwrap_or(key)
+}
 
-    Err(())
+/** Keys that have no template in the locale */
+pub fn untranslated_keys(locale: &dyn Locale) -> Vec<&'static str> {
+    TranslationKey::ALL
+        .iter()
+        .map(|key| -> &'static str { key.into() })
+        .filter(|key| locale.template(key).is_none())
+        .collect()
 }